@@ -1,12 +1,21 @@
 //! This module contains the core functionality of the project:
 //! Maze Generation and Maze Solving algorithms.
 
-use std::collections::{HashMap, VecDeque};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::ds;
-use crate::ds::graphs::{self, Graph};
+use crate::ds::graphs::generators::{self, MazeGenerator};
+use crate::ds::graphs;
+use crate::ds::graphs::algo;
+use crate::error::{ErrorKind, MazeError, Result};
 use crate::graphics;
 use crate::helper;
+use crate::io;
 
 ///
 /// The actual maze itself - both in terms of data to be manipulated in the form
@@ -17,8 +26,13 @@ pub struct Maze {
     height: usize,
     width: usize,
     maze_data: ds::MazeData,
+    tiling: ds::Tiling,
+    depth: usize,
+    level: usize,
     spanning_tree: Box<dyn graphs::Graph>,
     maze_state: HashMap<usize, ds::CellData>,
+    seed: u64,
+    braid: f64,
     maze_solved: bool,
     maze_solved_path: Vec<usize>,
     longest_path_solved: bool,
@@ -32,17 +46,74 @@ impl Maze {
     /// This returns a new maze instance which is used for all further
     /// operations.
     pub fn initialize_maze(height: usize, width: usize) -> Self {
+        Maze::initialize_maze_with(height, width, ds::Tiling::Square)
+    }
+
+    /// Populate the cells of a maze whose cells have the shape of the given
+    /// tiling. Rows of hexagonal cells overlap, sharing their diagonal walls,
+    /// and every odd row is shifted half a cell to the east. Triangular cells
+    /// share their slopes with the cells beside them, and their bases with the
+    /// rows above and below. Polar mazes cannot be drawn on the screen, so
+    /// their cells are located by ring and position around the ring instead.
+    pub fn initialize_maze_with(height: usize, width: usize, tiling: ds::Tiling) -> Self {
         let mut cells: Vec<Vec<ds::CellData>> = Vec::new();
         for _ in 0..height {
             cells.push(Vec::new());
         }
 
-        let mut x = graphics::LINE_INIT;
+        if tiling == ds::Tiling::Polar {
+            let mut id = 0;
+            for (r, (row, ring_width)) in cells
+                .iter_mut()
+                .zip(tiling.get_row_widths(height, width))
+                .enumerate()
+            {
+                for c in 0..ring_width {
+                    row.push(ds::CellData::with_shape(
+                        id,
+                        ds::Point::new(r, c),
+                        ds::CellShape::Sector,
+                    ));
+                    id += 1;
+                }
+            }
+        }
+
         let mut y = graphics::COL_INIT;
 
-        for i in 0..height {
+        for (i, row) in cells.iter_mut().enumerate() {
+            // the line and column of the first cell of the row, and the
+            // column increment, for cells which share walls between rows
+            let overlapping = match tiling {
+                ds::Tiling::Square => None,
+                ds::Tiling::Hex => Some((
+                    graphics::LINE_INIT + i * graphics::HEX_LINE_OFFSET,
+                    graphics::COL_INIT + (i % 2) * graphics::HEX_ROW_SHIFT,
+                    graphics::HEX_COL_OFFSET,
+                )),
+                // leaving a line for the bases of the first row
+                ds::Tiling::Delta => Some((
+                    graphics::LINE_INIT + 1 + i * graphics::TRIANGLE_LINE_OFFSET,
+                    graphics::COL_INIT,
+                    graphics::TRIANGLE_COL_OFFSET,
+                )),
+                ds::Tiling::Polar => continue,
+            };
+
+            if let Some((x, y, col_offset)) = overlapping {
+                for j in 0..width {
+                    row.push(ds::CellData::with_shape(
+                        width * i + j,
+                        ds::Point::new(x, y + j * col_offset),
+                        tiling.get_cell_shape(i, j),
+                    ));
+                }
+                continue;
+            }
+
+            let x = graphics::LINE_INIT + i * graphics::LINE_OFFSET;
             for j in 0..width {
-                cells[i].push(ds::CellData::new(
+                row.push(ds::CellData::new(
                     width * i + j,
                     ds::Point::new(i + x, j + y),
                 ));
@@ -51,15 +122,19 @@ impl Maze {
             // reset the y component - note that in this coordinate system, the Y-axis is along the
             // horizontal line from the origin, and the X-Axis is the vertical line from the origin
             y = graphics::COL_INIT;
-            x += graphics::LINE_OFFSET;
         }
 
         let mut new_maze = Maze {
-            height: height,
-            width: width,
-            maze_data: ds::MazeData::new(height, width, cells),
+            height,
+            width,
+            maze_data: ds::MazeData::with_tiling(height, width, tiling, cells),
+            tiling,
+            depth: 1,
+            level: 0,
             spanning_tree: Box::new(graphs::AdjacencySet::dummy()),
             maze_state: HashMap::new(),
+            seed: rand::thread_rng().gen(),
+            braid: 0.0,
             maze_solved: false,
             maze_solved_path: Vec::new(),
             longest_path_solved: false,
@@ -69,69 +144,238 @@ impl Maze {
         // Map from the coordinates of each cell to the corresponding
         // CellData structure
         for i in 0..new_maze.height {
-            for j in 0..new_maze.width {
+            for j in 0..new_maze.maze_data.get_row_width(i) {
+                let cell = *new_maze.maze_data.get_cell(i, j);
+                new_maze.maze_state.insert(cell.get_id(), cell);
+            }
+        }
+
+        // return the newly created maze instance
+        new_maze
+    }
+
+    /// Populate the cells of a maze of `depth` levels of square cells, stacked one
+    /// above the other. Only one level is shown at a time, always in the same place
+    /// on the screen, so each cell shares its location with the cells directly above
+    /// and below it.
+    pub fn initialize_maze_with_levels(height: usize, width: usize, depth: usize) -> Self {
+        let mut new_maze = Maze::initialize_maze_with(height, width, ds::Tiling::Square);
+        new_maze.depth = depth;
+
+        let area = height * width;
+        for level in 1..depth {
+            for v in 0..area {
+                let location = *new_maze.maze_state[&v].get_location();
+                let id = area * level + v;
                 new_maze
                     .maze_state
-                    .insert(new_maze.width * i + j, *new_maze.maze_data.get_cell(i, j));
+                    .insert(id, ds::CellData::new(id, location));
             }
         }
 
-        // return the newly created maze instance
         new_maze
     }
 
     ///
     /// Run the spanning tree algorithm, and generate a brand new maze from the grid
     /// created through initialize_maze
-    ///<
-    pub fn create_maze(&mut self) {
-        let mut graph =
-            graphs::AdjacencySet::new(self.height * self.width, graphs::GraphType::UNDIRECTED);
-
-        // add horizontal connections between cells as edges
-        for i in 0..self.height {
-            for j in 0..self.width - 1 {
-                graph.add_edge(self.width * i + j, self.width * i + j + 1);
-            }
+    ///
+    pub fn create_maze(&mut self) -> Result<()> {
+        self.create_maze_with(&generators::Prim::default())
+    }
+
+    ///
+    /// Generate a brand new maze from the grid created through initialize_maze, using
+    /// the given maze generation algorithm to produce the spanning tree. All random
+    /// choices are drawn from a generator seeded with the seed of this maze, so the
+    /// same seed and generator always produce the same maze.
+    ///
+    /// If a braid fraction has been set, that fraction of the dead ends is then
+    /// removed, so that the maze is no longer a spanning tree but contains cycles.
+    ///
+    /// Generators of directed mazes are given a grid with an arc each way between
+    /// neighbouring cells, and produce mazes of one-way doors, which cannot be
+    /// braided, and are only drawn with square cells.
+    ///
+    /// A maze of several levels is carved through all of them at once, with stairs
+    /// wherever a passage leads from one level to the next, and the first level is
+    /// shown.
+    ///
+    /// If the maze has been set to wrap around, the passages may also lead out of
+    /// one edge of the maze and in at the opposite edge.
+    ///
+    pub fn create_maze_with(&mut self, generator: &dyn MazeGenerator) -> Result<()> {
+        let (h, w) = (self.height, self.width);
+
+        if !generator.supports_tiling(self.tiling) {
+            return Err(MazeError::of(ErrorKind::InvalidTiling));
+        }
+        if self.depth > 1 && !generator.supports_levels() {
+            return Err(MazeError::of(ErrorKind::InvalidLevels));
+        }
+
+        let wrap = self.get_wrap();
+        if wrap != ds::Wrap::None && (self.tiling != ds::Tiling::Square || self.depth > 1) {
+            return Err(MazeError::of(ErrorKind::InvalidWrap));
         }
 
-        // add vertical connections between cells as edges
-        for i in 0..self.height - 1 {
-            for j in 0..self.width {
-                graph.add_edge(self.width * i + j, self.width * i + j + self.width);
+        let graph: Box<dyn graphs::Graph> = match (self.tiling, generator.get_graph_type()) {
+            (_, graphs::GraphType::DIRECTED) if self.braid > 0.0 => {
+                return Err(MazeError::of(ErrorKind::InvalidGraphType));
+            }
+            (_, kind) if self.depth > 1 => {
+                Box::new(graphs::AdjacencySet::levelled_grid(h, w, self.depth, kind))
+            }
+            (_, kind) if wrap != ds::Wrap::None => {
+                Box::new(graphs::AdjacencySet::wrapped_grid(h, w, wrap, kind))
+            }
+            (ds::Tiling::Square, graphs::GraphType::DIRECTED) => {
+                Box::new(graphs::AdjacencySet::directed_grid(h, w))
+            }
+            (_, graphs::GraphType::DIRECTED) => {
+                return Err(MazeError::of(ErrorKind::InvalidTiling));
             }
+            (ds::Tiling::Square, graphs::GraphType::UNDIRECTED) => {
+                Box::new(graphs::BitGrid::grid(h, w))
+            }
+            (tiling, graphs::GraphType::UNDIRECTED) => Box::new(
+                graphs::AdjacencySet::tiled_grid(h, w, tiling, graphs::GraphType::UNDIRECTED),
+            ),
+        };
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        self.spanning_tree = generator.generate(graph.as_ref(), &mut rng)?;
+        if self.braid > 0.0 {
+            generators::braid(
+                self.spanning_tree.as_mut(),
+                graph.as_ref(),
+                self.braid,
+                &mut rng,
+            )?;
         }
 
-        // get the spanning tree starting from the top-left corner of the
-        // maze
-        self.spanning_tree = graph.get_spanning_tree(0);
+        self.level = 0;
+        self.draw_level()
+    }
 
-        // display the maze as a set of cells with walls erased between the cells
-        // forming part of the spanning tree
-        self.draw_maze();
+    /// Display the level of the maze being shown as a set of cells with walls erased
+    /// between the cells forming part of the spanning tree, along with the seed needed
+    /// to generate it again.
+    fn draw_level(&self) -> Result<()> {
+        if self.tiling.is_drawable_on_terminal() {
+            self.draw_maze();
+        }
+        io::println_message(&format!("Seed: {}", self.seed));
+        if self.depth > 1 {
+            io::println_message(&format!("Level: {} of {}", self.level + 1, self.depth));
+        }
 
-        let mut visited = vec![false; self.height * self.width];
-        self.create_maze_helper(&mut visited, 0);
+        if self.tiling.is_drawable_on_terminal() {
+            self.create_maze_helper(0)?;
+        }
+        Ok(())
     }
 
     /// Helper function to erase the right walls in the current state of the
-    /// maze using DFS
-    fn create_maze_helper(&self, visited: &mut Vec<bool>, source_vertex: usize) {
-        if visited[source_vertex] {
-            return;
+    /// maze, visiting the cells in depth-first order. Passages which only lead
+    /// one way are marked with the direction they lead in, and stairs to the
+    /// other levels with the way they lead.
+    fn create_maze_helper(&self, source_vertex: usize) -> Result<()> {
+        let tree = algo::dfs(self.spanning_tree.as_ref(), source_vertex)?;
+
+        for &vertex in tree.get_order() {
+            if self.get_level_of(vertex) != self.level {
+                continue;
+            }
+
+            for neighbour in self.spanning_tree.neighbours(vertex) {
+                if self.get_level_of(neighbour) != self.level {
+                    continue;
+                }
+
+                let source_cell = self.maze_state.get(&vertex).unwrap();
+                let direction = self.get_direction(vertex, neighbour);
+                if self.spanning_tree.has_edge(neighbour, vertex) {
+                    graphics::renderer::erase_wall(source_cell, &direction);
+                } else {
+                    graphics::renderer::mark_one_way(source_cell, &direction);
+                }
+            }
+
+            self.mark_stairs(vertex);
         }
 
-        visited[source_vertex] = true;
-        for neighbour in self.spanning_tree.get_adjacent_vertices(source_vertex) {
-            let source_cell = self.maze_state.get(&source_vertex).unwrap();
-            let neighbour_cell = self.maze_state.get(&neighbour).unwrap();
+        Ok(())
+    }
+
+    /// the level of the maze that the given cell lies on
+    fn get_level_of(&self, vertex: usize) -> usize {
+        vertex / (self.maze_state.len() / self.depth)
+    }
 
-            let direction = helper::get_direction(&source_cell, &neighbour_cell);
-            graphics::renderer::erase_wall(source_cell, &direction);
-            self.create_maze_helper(visited, neighbour);
+    /// the direction from one cell to a neighbouring cell, which may lie
+    /// directly above or below it on another level, or at the far end of a
+    /// row or column which wraps around
+    fn get_direction(&self, from: usize, to: usize) -> ds::Direction {
+        match self.get_level_of(from).cmp(&self.get_level_of(to)) {
+            Ordering::Less => ds::Direction::Up,
+            Ordering::Greater => ds::Direction::Down,
+            Ordering::Equal if self.is_wrapped(from, to) => {
+                helper::get_direction(&self.maze_state[&from], &self.maze_state[&to]).opposite()
+            }
+            Ordering::Equal => {
+                helper::get_direction(&self.maze_state[&from], &self.maze_state[&to])
+            }
         }
     }
 
+    /// whether the passage between the given neighbouring cells leads out of
+    /// one edge of the maze and in at the opposite edge
+    fn is_wrapped(&self, from: usize, to: usize) -> bool {
+        if self.get_wrap() == ds::Wrap::None {
+            return false;
+        }
+
+        let w = self.width;
+        let ((i1, j1), (i2, j2)) = ((from / w, from % w), (to / w, to % w));
+        (i1 == i2 && j1.abs_diff(j2) > 1) || (j1 == j2 && i1.abs_diff(i2) > 1)
+    }
+
+    /// mark the given cell with the stairs leading out of it, if any
+    fn mark_stairs(&self, vertex: usize) {
+        let (area, level) = (self.maze_state.len() / self.depth, self.get_level_of(vertex));
+        let up = level + 1 < self.depth && self.spanning_tree.has_edge(vertex, vertex + area);
+        let down = level > 0 && self.spanning_tree.has_edge(vertex, vertex - area);
+
+        let sprite = match (up, down) {
+            (true, true) => graphics::STAIRS_SPRITE,
+            (true, false) => graphics::STAIRS_UP_SPRITE,
+            (false, true) => graphics::STAIRS_DOWN_SPRITE,
+            (false, false) => return,
+        };
+        graphics::renderer::mark_stairs(&self.maze_state[&vertex], sprite);
+    }
+
+    ///
+    /// Show the given level of a maze of several levels, along with the part of the
+    /// path through the maze currently rendered, if any, lying on that level.
+    ///
+    pub fn show_level(&mut self, level: usize) -> Result<()> {
+        if level >= self.depth {
+            return Err(MazeError::of(ErrorKind::InvalidLevels));
+        }
+
+        self.level = level;
+        self.draw_level()?;
+        if self.maze_solved {
+            self.render_path(&self.maze_solved_path);
+        } else if self.longest_path_solved {
+            self.render_path(&self.longest_path_solved_path);
+        }
+
+        Ok(())
+    }
+
     /// Dispatch to the Renderer to display the current state of
     /// the maze (the MazeData instance)
     pub fn draw_maze(&self) {
@@ -139,113 +383,71 @@ impl Maze {
     }
 
     ///
-    /// Solve the current state of the maze. This simply uses DFS to plot the only path from
-    /// the top-left corner of the maze to the bottom-right corner of the maze.
+    /// Solve the current state of the maze. This uses BFS to plot the shortest path from
+    /// the top-left corner of the maze to the bottom-right corner of the maze - in a perfect
+    /// maze this is the only path, but a braided maze may have many. A polar maze is solved
+    /// from its centre to the last cell of its outermost ring, and a maze of several levels
+    /// from the first level to the last. This fails if the maze has not been created yet.
     ///
-    pub fn solve_maze(&mut self) {
-        self.prime_solved_states();
-
-        let mut visited = vec![false; self.height * self.width];
-        let mut path = Vec::new();
+    pub fn solve_maze(&mut self) -> Result<()> {
+        let target = self.maze_state.len() - 1;
 
-        let h = self.height;
-        let w = self.width;
-
-        self.solve_maze_helper(&mut visited, 0, h * w - 1, &mut path);
-    }
-
-    fn solve_maze_helper(
-        &mut self,
-        visited: &mut Vec<bool>,
-        source: usize,
-        target: usize,
-        path: &mut Vec<usize>,
-    ) {
-        visited[source] = true;
-        path.push(source);
-
-        // if we have reached the target, then
-        // process the path and return
-        if source == target {
-            self.render_path(&path);
-            self.maze_solved = true;
-            self.maze_solved_path = path.clone();
-            return;
-        }
+        let path = self.solve_maze_helper(0, target)?;
 
-        for neighbour in self.spanning_tree.get_adjacent_vertices(source) {
-            if !visited[neighbour] {
-                self.solve_maze_helper(visited, neighbour, target, path);
-            }
-        }
+        self.prime_solved_states();
+        self.render_path(&path);
+        self.maze_solved = true;
+        self.maze_solved_path = path;
+        Ok(())
+    }
 
-        // Didn't reach the target yet. Try the next vertex in line after
-        // discarding the current vertex in the path.
-        path.pop().unwrap();
-        visited[source] = false;
+    /// Find the shortest path from `source` to `target` using BFS
+    fn solve_maze_helper(&self, source: usize, target: usize) -> Result<Vec<usize>> {
+        algo::bfs(self.spanning_tree.as_ref(), source)?
+            .path_to(target)
+            .ok_or_else(|| MazeError::of(ErrorKind::DisconnectedGraph))
     }
 
     /// render the given path by drawing each cell
     /// along the path of the given solution to
-    /// the maze
-    fn render_path(&self, path: &Vec<usize>) {
-        // special handling for a single cell maze
-        // and a two cell maze
-        if path.len() == 1 {
-            let cell = self.maze_state.get(&path[0]).unwrap();
-            graphics::renderer::fill_cell(&cell, 's');
-        } else if path.len() == 2 {
-            let start_cell = self.maze_state.get(&path[0]).unwrap();
-            let end_cell = self.maze_state.get(&path[1]).unwrap();
-            graphics::renderer::fill_cell(&start_cell, 's');
-            graphics::renderer::fill_cell(&end_cell, 't');
-        } else {
-            for i in 0..path.len() - 1 {
-                let (curr_cell, next_cell) = (
-                    self.maze_state.get(&path[i]).unwrap(),
-                    self.maze_state.get(&path[i + 1]).unwrap(),
-                );
-
-                let direction = helper::get_direction(&curr_cell, &next_cell);
-
-                // handle `source` and `target` cells, and handle the
-                // general case separately
-                if i == 0 {
-                    graphics::renderer::fill_cell(&curr_cell, 's');
-                } else if i == path.len() - 2 {
-                    graphics::renderer::fill_cell(
-                        &curr_cell,
-                        helper::get_char_for_direction(&direction),
-                    );
-                    graphics::renderer::fill_cell(next_cell, 't');
-                } else {
-                    graphics::renderer::fill_cell(
-                        &curr_cell,
-                        helper::get_char_for_direction(&direction),
-                    );
-                }
-            }
+    /// the maze which lies on the level shown
+    fn render_path(&self, path: &[usize]) {
+        if !self.tiling.is_drawable_on_terminal() {
+            return;
         }
-    }
 
-    /// Clear an already rendered path from the screen
-    fn clear_path(&self, path: &Vec<usize>) {
-        for i in 0..path.len() - 1 {
-            let (curr_cell, next_cell) = (
-                self.maze_state.get(&path[i]).unwrap(),
-                self.maze_state.get(&path[i + 1]).unwrap(),
-            );
+        for (i, &vertex) in path.iter().enumerate() {
+            if self.get_level_of(vertex) != self.level {
+                continue;
+            }
 
             // handle `source` and `target` cells, and handle the
             // general case separately
-            if i == 0 {
-                graphics::renderer::clear_cell(&curr_cell);
-            } else if i == path.len() - 2 {
-                graphics::renderer::clear_cell(&curr_cell);
-                graphics::renderer::clear_cell(&next_cell);
-                break;
+            let c = if i == 0 {
+                's'
+            } else if i == path.len() - 1 {
+                't'
             } else {
-                graphics::renderer::clear_cell(&curr_cell);
+                let direction = self.get_direction(vertex, path[i + 1]);
+                helper::get_char_for_direction(&direction)
+            };
+
+            let cell = self.maze_state.get(&vertex).unwrap();
+            graphics::renderer::fill_cell(cell, c);
+        }
+    }
+
+    /// Clear an already rendered path from the screen, marking
+    /// the stairs along it again
+    fn clear_path(&self, path: &[usize]) {
+        if !self.tiling.is_drawable_on_terminal() {
+            return;
+        }
+
+        for &vertex in path {
+            if self.get_level_of(vertex) == self.level {
+                graphics::renderer::clear_cell(self.maze_state.get(&vertex).unwrap());
+                self.mark_stairs(vertex);
             }
         }
     }
@@ -263,90 +465,106 @@ impl Maze {
     }
 
     ///
-    /// Find a longest path in the maze using two BFS runs - the
-    /// first run produces the endpoint of a longest path, and then
-    /// the second run from this endpoint produces a longest path
-    /// (which may be unique, of course).
+    /// Find a longest path in the maze using two BFS runs - see
+    /// `algo::tree_diameter`. In a braided maze this is instead a
+    /// pair of cells which are far apart, along with the shortest
+    /// path between them. This fails if the maze has not been
+    /// created yet.
     ///
-    pub fn print_longest_path(&mut self) {
-        self.prime_solved_states();
-
-        // find a longest path from the source
-        let first_run_path = self.find_longest_path(0);
-        let longest_path_start = first_run_path[0];
-
-        let longest_path = self.find_longest_path(longest_path_start);
+    pub fn print_longest_path(&mut self) -> Result<()> {
+        let longest_path = algo::tree_diameter(self.spanning_tree.as_ref(), 0)?;
 
+        self.prime_solved_states();
         self.render_path(&longest_path);
         self.longest_path_solved = true;
-        self.longest_path_solved_path = longest_path.clone();
+        self.longest_path_solved_path = longest_path;
+        Ok(())
     }
 
-    fn find_longest_path(&self, start: usize) -> Vec<usize> {
-        let mut queue = VecDeque::new();
-        queue.push_back(start);
+    ///
+    /// Save the maze as an SVG image, along with the path through it which is
+    /// currently rendered on the screen, if any.
+    ///
+    pub fn save_svg(&self, filename: &str) -> Result<()> {
+        let path: &[usize] = if self.maze_solved {
+            &self.maze_solved_path
+        } else if self.longest_path_solved {
+            &self.longest_path_solved_path
+        } else {
+            &[]
+        };
 
-        // distance table - the rows are the vertices of the spanning tree,
-        // and the 0th column is the distance of this vertex, and the 1st
-        // column is the last vertex to the current vertex.
-        let mut distance_table = vec![vec![-1isize; 2]; self.spanning_tree.size()];
+        let svg = graphics::svg::render(&self.maze_data, self.spanning_tree.as_ref(), path);
+        fs::write(filename, svg).map_err(|e| {
+            let message = format!("Error: could not write {}: {}", filename, e);
+            MazeError::new(ErrorKind::InvalidOutputFile, message)
+        })
+    }
 
-        distance_table[start][0] = 0;
-        distance_table[start][1] = start as isize;
+    ///
+    /// getters for maze properties
+    ///
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
 
-        while !queue.is_empty() {
-            let vertex = queue.pop_front().unwrap();
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
 
-            for neighbour in self.spanning_tree.get_adjacent_vertices(vertex) {
-                // if the neighbour has not been visited yet, update its distance
-                // and last vertex info
-                if distance_table[neighbour][0] == -1 {
-                    distance_table[neighbour][0] = distance_table[vertex][0] + 1;
-                    distance_table[neighbour][1] = vertex as isize;
-                    queue.push_back(neighbour);
-                }
-            }
-        }
+    pub fn get_tiling(&self) -> ds::Tiling {
+        self.tiling
+    }
 
-        let mut farthest_vertex = 0;
-        let mut max_distance = -1;
-        for i in 0..self.spanning_tree.size() {
-            if distance_table[i][0] > max_distance {
-                max_distance = distance_table[i][0];
-                farthest_vertex = i;
-            }
-        }
+    pub fn get_wrap(&self) -> ds::Wrap {
+        self.maze_data.get_wrap()
+    }
 
-        // now chart the path by backtracking from
-        // the farthest vertex to the starting vertex
-        let mut longest_path = Vec::new();
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
 
-        let mut stack = VecDeque::new();
-        stack.push_front(farthest_vertex);
+    /// the level of the maze being shown
+    pub fn get_level(&self) -> usize {
+        self.level
+    }
 
-        let mut last_vertex = farthest_vertex;
-        while last_vertex != start {
-            stack.push_front(last_vertex);
-            last_vertex = distance_table[last_vertex][1] as usize;
-        }
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
 
-        stack.push_front(start);
+    /// set the seed used to generate the maze - this must be
+    /// called before the maze is created
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
 
-        while !stack.is_empty() {
-            longest_path.push(stack.pop_front().unwrap());
-        }
+    pub fn get_braid(&self) -> f64 {
+        self.braid
+    }
 
-        longest_path
+    /// set the fraction (between 0 and 1) of dead ends to remove when
+    /// the maze is created - this must be called before the maze is created
+    pub fn set_braid(&mut self, fraction: f64) {
+        self.braid = fraction;
     }
 
-    ///
-    /// getters for maze properties
-    ///
-    pub fn get_height(&self) -> usize {
-        self.height
+    /// wrap the edges of a maze of square cells around to the opposite edges
+    /// when the maze is created - this must be called before the maze is created
+    pub fn set_wrap(&mut self, wrap: ds::Wrap) {
+        self.maze_data.set_wrap(wrap);
     }
+}
 
-    pub fn get_width(&self) -> usize {
-        self.width
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_before_create() {
+        let mut maze = Maze::initialize_maze(2, 3);
+
+        assert!(maze.solve_maze().is_err());
+        assert!(maze.print_longest_path().is_err());
     }
 }
@@ -0,0 +1,120 @@
+//! This submodule provides a disjoint-set (union-find) data structure.
+
+///
+/// A disjoint-set forest over the elements 0..n, using path compression
+/// and union by rank so that both operations run in near-constant
+/// amortised time.
+///
+#[derive(Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// create a new disjoint-set where each element is in a set of its own
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            count: n,
+        }
+    }
+
+    /// find the representative of the set containing `x`, compressing
+    /// the path from `x` to the root along the way
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// merge the sets containing `x` and `y`. Returns `false` if they
+    /// were already in the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (rx, ry) = (self.find(x), self.find(y));
+        if rx == ry {
+            return false;
+        }
+
+        if self.rank[rx] < self.rank[ry] {
+            self.parent[rx] = ry;
+        } else if self.rank[rx] > self.rank[ry] {
+            self.parent[ry] = rx;
+        } else {
+            self.parent[ry] = rx;
+            self.rank[rx] += 1;
+        }
+        self.count -= 1;
+
+        true
+    }
+
+    /// check whether `x` and `y` are in the same set
+    pub fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// the number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// the number of elements
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set_sanity() {
+        let mut set = DisjointSet::new(5);
+
+        assert_eq!(set.size(), 5);
+        assert_eq!(set.count(), 5);
+        assert!(!set.connected(0, 1));
+    }
+
+    #[test]
+    fn test_disjoint_set_union() {
+        let mut set = DisjointSet::new(6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.count(), 3);
+    }
+
+    #[test]
+    fn test_disjoint_set_path_compression() {
+        let mut set = DisjointSet::new(4);
+
+        set.union(0, 1);
+        set.union(2, 3);
+        set.union(0, 2);
+
+        let root = set.find(3);
+        for x in 0..4 {
+            assert_eq!(set.find(x), root);
+        }
+    }
+}
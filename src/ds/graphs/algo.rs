@@ -0,0 +1,492 @@
+//! Traversals and path finding over any `Graph` implementation. These only
+//! compute distances and paths - rendering them is left to the caller.
+
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::{Edge, Graph, GraphType, Neighbours};
+use crate::ds::disjoint_set::DisjointSet;
+use crate::error::*;
+
+///
+/// The result of searching a graph from a source vertex: the distance of
+/// every vertex reached from the source, the vertex from which it was
+/// reached, and the order in which the vertices were reached. Vertices which
+/// were not reached have neither a distance nor a predecessor.
+///
+/// Distances are counted in edges, except for `dijkstra`, which sums the
+/// weights of the edges.
+///
+#[derive(Debug, Clone)]
+pub struct SearchTree<D = usize> {
+    source: usize,
+    distances: Vec<Option<D>>,
+    predecessors: Vec<Option<usize>>,
+    order: Vec<usize>,
+}
+
+impl<D: Copy + PartialOrd> SearchTree<D> {
+    fn new(n: usize, source: usize) -> Self {
+        SearchTree {
+            source,
+            distances: vec![None; n],
+            predecessors: vec![None; n],
+            order: Vec::new(),
+        }
+    }
+
+    /// record that `v` has been reached at the given distance
+    fn reach(&mut self, v: usize, predecessor: Option<usize>, distance: D) {
+        self.distances[v] = Some(distance);
+        self.predecessors[v] = predecessor;
+        self.order.push(v);
+    }
+
+    // getters
+
+    pub fn get_source(&self) -> usize {
+        self.source
+    }
+
+    /// the distance of `v` from the source, if it was reached
+    pub fn get_distance(&self, v: usize) -> Option<D> {
+        self.distances.get(v).copied().flatten()
+    }
+
+    /// the vertex from which `v` was reached - the source has none
+    pub fn get_predecessor(&self, v: usize) -> Option<usize> {
+        self.predecessors.get(v).copied().flatten()
+    }
+
+    pub fn get_distances(&self) -> &[Option<D>] {
+        &self.distances
+    }
+
+    pub fn get_predecessors(&self) -> &[Option<usize>] {
+        &self.predecessors
+    }
+
+    /// the vertices reached, in the order in which they were reached
+    pub fn get_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// whether `v` was reached from the source
+    pub fn reached(&self, v: usize) -> bool {
+        self.get_distance(v).is_some()
+    }
+
+    /// the path from the source to `target`, found by backtracking along the
+    /// predecessors, if the target was reached
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        if !self.reached(target) {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut vertex = target;
+        while let Some(predecessor) = self.predecessors[vertex] {
+            path.push(predecessor);
+            vertex = predecessor;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// the reached vertex farthest from the source - the lowest such vertex
+    /// if there are several
+    pub fn farthest(&self) -> usize {
+        let mut farthest = self.source;
+        for (v, distance) in self.distances.iter().enumerate() {
+            if let Some(distance) = *distance {
+                if distance > self.distances[farthest].unwrap() {
+                    farthest = v;
+                }
+            }
+        }
+
+        farthest
+    }
+}
+
+fn check_source(graph: &dyn Graph, source: usize) -> Result<()> {
+    if source >= graph.size() {
+        return Err(MazeError::of(ErrorKind::InvalidVertexOrVertices));
+    }
+
+    Ok(())
+}
+
+/// breadth-first search from `source`, which finds the shortest path (in
+/// edges) to every vertex reachable from it
+pub fn bfs(graph: &dyn Graph, source: usize) -> Result<SearchTree> {
+    check_source(graph, source)?;
+
+    let mut tree = SearchTree::new(graph.size(), source);
+    tree.reach(source, None, 0);
+
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(vertex) = queue.pop_front() {
+        let distance = tree.distances[vertex].unwrap() + 1;
+
+        for neighbour in graph.neighbours(vertex) {
+            if !tree.reached(neighbour) {
+                tree.reach(neighbour, Some(vertex), distance);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+/// depth-first search from `source`, exploring the lowest neighbour of each
+/// vertex first. The distance of each vertex is its depth in the search
+/// tree. This uses an explicit stack so that large graphs do not overflow
+/// the call stack.
+pub fn dfs(graph: &dyn Graph, source: usize) -> Result<SearchTree> {
+    check_source(graph, source)?;
+
+    let mut tree = SearchTree::new(graph.size(), source);
+    let mut stack = vec![(source, None)];
+
+    while let Some((vertex, predecessor)) = stack.pop() {
+        if tree.reached(vertex) {
+            continue;
+        }
+
+        let distance = predecessor.map_or(0, |p: usize| tree.distances[p].unwrap() + 1);
+        tree.reach(vertex, predecessor, distance);
+
+        // pushed in reverse, so that the lowest neighbour is popped first
+        for neighbour in graph.neighbours(vertex).rev() {
+            if !tree.reached(neighbour) {
+                stack.push((neighbour, Some(vertex)));
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Dijkstra's Algorithm from `source`, which finds the lightest path to
+/// every vertex reachable from it, using the weights given by
+/// `Graph::get_weight`. The weights must not be negative.
+pub fn dijkstra(graph: &dyn Graph, source: usize) -> Result<SearchTree<f64>> {
+    check_source(graph, source)?;
+
+    let mut tree = SearchTree::new(graph.size(), source);
+    let mut tentative = vec![f64::INFINITY; graph.size()];
+    tentative[source] = 0.0;
+
+    // the `from` of each entry is the predecessor of `to`, and its weight
+    // the distance of `to` along that path
+    let mut heap = BinaryHeap::new();
+    heap.push(Edge::new(source, source, 0.0));
+
+    while let Some(Edge { from, to, weight }) = heap.pop() {
+        if tree.reached(to) {
+            continue;
+        }
+
+        let predecessor = if to == source { None } else { Some(from) };
+        tree.reach(to, predecessor, weight);
+
+        for neighbour in graph.neighbours(to) {
+            let distance = weight + graph.get_weight(to, neighbour);
+            if !tree.reached(neighbour) && distance < tentative[neighbour] {
+                tentative[neighbour] = distance;
+                heap.push(Edge::new(to, neighbour, distance));
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+/// label every vertex with its connected component, returning the number of
+/// components along with the labels. Components are numbered from 0 in the
+/// order of their lowest vertex. The edges of a directed graph are followed
+/// either way, so its components are the weakly connected ones.
+pub fn connected_components(graph: &dyn Graph) -> (usize, Vec<usize>) {
+    let n = graph.size();
+
+    let mut components = DisjointSet::new(n);
+    for v in 0..n {
+        for neighbour in graph.neighbours(v) {
+            components.union(v, neighbour);
+        }
+    }
+
+    let mut labels = vec![usize::MAX; n];
+    let mut count = 0;
+    for v in 0..n {
+        let root = components.find(v);
+        if labels[root] == usize::MAX {
+            labels[root] = count;
+            count += 1;
+        }
+        labels[v] = labels[root];
+    }
+
+    (count, labels)
+}
+
+/// the state of a vertex during the search for a directed cycle
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Colour {
+    Unvisited,
+    OnStack,
+    Done,
+}
+
+/// whether the graph contains a cycle. An undirected graph has one when it
+/// has more edges than a forest over its components, while a directed graph
+/// has one when a depth-first search meets a vertex still on its stack.
+pub fn has_cycle(graph: &dyn Graph) -> bool {
+    let n = graph.size();
+
+    if graph.get_kind() == GraphType::UNDIRECTED {
+        let (count, _) = connected_components(graph);
+        return graph.edge_count() + count > n;
+    }
+
+    let mut colours = vec![Colour::Unvisited; n];
+    for root in 0..n {
+        if colours[root] != Colour::Unvisited {
+            continue;
+        }
+
+        colours[root] = Colour::OnStack;
+        let mut stack: Vec<(usize, Neighbours<'_>)> = vec![(root, graph.neighbours(root))];
+
+        while let Some((vertex, neighbours)) = stack.last_mut() {
+            match neighbours.next() {
+                Some(neighbour) => match colours[neighbour] {
+                    Colour::OnStack => return true,
+                    Colour::Unvisited => {
+                        colours[neighbour] = Colour::OnStack;
+                        stack.push((neighbour, graph.neighbours(neighbour)));
+                    }
+                    Colour::Done => {}
+                },
+                None => {
+                    colours[*vertex] = Colour::Done;
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// Find a longest path in a tree using two breadth-first searches - the
+/// first, from `source`, finds one end of a longest path, and the second,
+/// from that end, finds the path itself.
+///
+/// In a graph with cycles the paths found are shortest paths, so this is
+/// instead a pair of vertices which are far apart, along with the shortest
+/// path between them. In a directed graph the far end of the first path may
+/// lead nowhere, so the longer of the two paths is taken.
+pub fn tree_diameter(graph: &dyn Graph, source: usize) -> Result<Vec<usize>> {
+    let first_run = bfs(graph, source)?;
+    let start = first_run.farthest();
+    let first_path = first_run.path_to(start).unwrap();
+
+    let second_run = bfs(graph, start)?;
+    let second_path = second_run.path_to(second_run.farthest()).unwrap();
+
+    if second_path.len() >= first_path.len() {
+        Ok(second_path)
+    } else {
+        Ok(first_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::graphs::{AdjacencySet, WeightedAdjacencySet};
+
+    /// the undirected path 0 - 1 - ... - (n - 1)
+    fn path_graph(n: usize) -> AdjacencySet {
+        let mut g = AdjacencySet::new(n, GraphType::UNDIRECTED);
+        for v in 1..n {
+            g.add_edge(v - 1, v).unwrap();
+        }
+        g
+    }
+
+    #[test]
+    fn test_bfs_grid_distances() {
+        let (h, w) = (4, 6);
+        let grid = AdjacencySet::grid(h, w);
+
+        let tree = bfs(&grid, 0).unwrap();
+
+        for v in 0..h * w {
+            assert_eq!(tree.get_distance(v), Some(v / w + v % w));
+        }
+        assert_eq!(tree.get_predecessor(0), None);
+        assert_eq!(tree.get_order().len(), h * w);
+        assert_eq!(tree.farthest(), h * w - 1);
+
+        let path = tree.path_to(h * w - 1).unwrap();
+        assert_eq!(path.len(), h + w - 1);
+        assert_eq!((path[0], path[path.len() - 1]), (0, h * w - 1));
+        assert!(path.windows(2).all(|p| grid.has_edge(p[0], p[1])));
+    }
+
+    #[test]
+    fn test_bfs_unreachable() {
+        let mut g = AdjacencySet::new(4, GraphType::UNDIRECTED);
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(2, 3).unwrap();
+
+        let tree = bfs(&g, 1).unwrap();
+
+        assert!(tree.reached(0));
+        assert!(!tree.reached(2));
+        assert_eq!(tree.path_to(3), None);
+        assert_eq!(tree.get_order(), &[1, 0]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let grid = AdjacencySet::grid(3, 3);
+
+        let tree = dfs(&grid, 0).unwrap();
+
+        // always going to the lowest neighbour snakes along the rows
+        assert_eq!(tree.get_order(), &[0, 1, 2, 5, 4, 3, 6, 7, 8]);
+        assert_eq!(tree.get_distance(8), Some(8));
+        assert_eq!(tree.get_predecessor(3), Some(4));
+        assert_eq!(tree.path_to(8).unwrap(), tree.get_order().to_vec());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let (h, w) = (3, 4);
+
+        // the passages out of the middle row are costly
+        let g = WeightedAdjacencySet::grid(h, w, |v1, v2| {
+            if v1 / w == 1 || v2 / w == 1 {
+                if v1 % w == w - 1 && v2 % w == w - 1 {
+                    1.0
+                } else {
+                    10.0
+                }
+            } else {
+                1.0
+            }
+        });
+
+        let tree = dijkstra(&g, 0).unwrap();
+
+        // along the top row and down the last column
+        assert_eq!(tree.get_distance(h * w - 1), Some(5.0));
+        assert_eq!(tree.path_to(h * w - 1).unwrap(), vec![0, 1, 2, 3, 7, 11]);
+        assert_eq!(tree.get_distance(4), Some(10.0));
+    }
+
+    #[test]
+    fn test_dijkstra_unweighted_matches_bfs() {
+        let grid = AdjacencySet::grid(5, 5);
+
+        let weighted = dijkstra(&grid, 12).unwrap();
+        let unweighted = bfs(&grid, 12).unwrap();
+
+        for v in 0..grid.size() {
+            assert_eq!(
+                weighted.get_distance(v),
+                unweighted.get_distance(v).map(|d| d as f64)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_source() {
+        let g = path_graph(3);
+
+        assert!(bfs(&g, 3).is_err());
+        assert!(dfs(&g, 3).is_err());
+        assert!(dijkstra(&g, 3).is_err());
+        assert!(tree_diameter(&g, 3).is_err());
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut g = AdjacencySet::new(6, GraphType::DIRECTED);
+        g.add_edge(3, 0).unwrap();
+        g.add_edge(1, 4).unwrap();
+        g.add_edge(5, 4).unwrap();
+
+        let (count, labels) = connected_components(&g);
+
+        assert_eq!(count, 3);
+        assert_eq!(labels, vec![0, 1, 2, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_has_cycle_undirected() {
+        let mut g = path_graph(4);
+        assert!(!has_cycle(&g));
+
+        g.add_edge(3, 0).unwrap();
+        assert!(has_cycle(&g));
+
+        assert!(has_cycle(&AdjacencySet::grid(2, 2)));
+        assert!(!has_cycle(&AdjacencySet::new(3, GraphType::UNDIRECTED)));
+    }
+
+    #[test]
+    fn test_has_cycle_self_loop() {
+        let mut g = path_graph(3);
+        g.add_edge(1, 1).unwrap();
+        assert_eq!(g.edge_count(), 3);
+        assert!(has_cycle(&g));
+
+        let mut g = AdjacencySet::new(2, GraphType::DIRECTED);
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(1, 1).unwrap();
+        assert!(has_cycle(&g));
+    }
+
+    #[test]
+    fn test_has_cycle_directed() {
+        let mut g = AdjacencySet::new(4, GraphType::DIRECTED);
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(0, 2).unwrap();
+        g.add_edge(1, 3).unwrap();
+        g.add_edge(2, 3).unwrap();
+        assert!(!has_cycle(&g));
+
+        g.add_edge(3, 0).unwrap();
+        assert!(has_cycle(&g));
+    }
+
+    #[test]
+    fn test_tree_diameter() {
+        // a path of 5 vertices, with a branch of 2 off its middle
+        let mut g = path_graph(7);
+        g.remove_edge(4, 5).unwrap();
+        g.add_edge(2, 5).unwrap();
+
+        let diameter = tree_diameter(&g, 5).unwrap();
+
+        assert_eq!(diameter.len(), 5);
+        assert_eq!(diameter, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_tree_diameter_directed() {
+        let mut g = AdjacencySet::new(3, GraphType::DIRECTED);
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(1, 2).unwrap();
+
+        // the far end of the first run leads nowhere
+        assert_eq!(tree_diameter(&g, 0).unwrap(), vec![0, 1, 2]);
+    }
+}
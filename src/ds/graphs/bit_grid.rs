@@ -0,0 +1,285 @@
+//! A compact representation of grid mazes, for mazes too large to be held
+//! as an adjacency set.
+
+use rand::RngCore;
+
+use super::{minimum_spanning_tree, random_spanning_tree, Graph, Neighbours};
+use crate::error::*;
+
+/// the passage leading east out of a cell
+const EAST: u8 = 0b01;
+/// the passage leading south out of a cell
+const SOUTH: u8 = 0b10;
+
+/// the number of cells whose passages are packed into each word
+const CELLS_PER_WORD: usize = 32;
+
+///
+/// An undirected graph over the cells of a grid, where only neighbouring
+/// cells may be connected. Every cell owns the passages leading east and
+/// south out of it, so the whole maze takes two bits per cell, and the
+/// neighbours of a cell are found by testing at most four bits.
+///
+/// Cell `(i, j)` is vertex `width * i + j`, as in `AdjacencySet::grid`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    height: usize,
+    width: usize,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    /// create a grid of the given dimensions without any passages
+    pub fn new(height: usize, width: usize) -> Self {
+        let cells = height * width;
+
+        BitGrid {
+            height,
+            width,
+            bits: vec![0; (cells + CELLS_PER_WORD - 1) / CELLS_PER_WORD],
+        }
+    }
+
+    /// create the grid graph for a maze of the given dimensions - every cell
+    /// is connected to its horizontal and vertical neighbours
+    pub fn grid(height: usize, width: usize) -> Self {
+        let mut graph = BitGrid::new(height, width);
+
+        for v in 0..height * width {
+            if v % width + 1 < width {
+                graph.set(v, EAST);
+            }
+            if v / width + 1 < height {
+                graph.set(v, SOUTH);
+            }
+        }
+
+        graph
+    }
+
+    // getters
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// open the given passage out of cell `v`
+    fn set(&mut self, v: usize, passage: u8) {
+        let (word, shift) = (v / CELLS_PER_WORD, 2 * (v % CELLS_PER_WORD));
+        self.bits[word] |= u64::from(passage) << shift;
+    }
+
+    /// close the given passage out of cell `v`
+    fn clear(&mut self, v: usize, passage: u8) {
+        let (word, shift) = (v / CELLS_PER_WORD, 2 * (v % CELLS_PER_WORD));
+        self.bits[word] &= !(u64::from(passage) << shift);
+    }
+
+    /// whether the given passage out of cell `v` is open
+    fn is_set(&self, v: usize, passage: u8) -> bool {
+        let (word, shift) = (v / CELLS_PER_WORD, 2 * (v % CELLS_PER_WORD));
+        self.bits[word] & (u64::from(passage) << shift) != 0
+    }
+
+    /// the cell owning the passage between `v1` and `v2`, and which of its
+    /// passages it is, if the cells are neighbours
+    fn passage(&self, v1: usize, v2: usize) -> Option<(usize, u8)> {
+        let (v, u) = (v1.min(v2), v1.max(v2));
+
+        if u >= self.size() {
+            None
+        } else if u == v + 1 && u % self.width != 0 {
+            Some((v, EAST))
+        } else if u == v + self.width {
+            Some((v, SOUTH))
+        } else {
+            None
+        }
+    }
+}
+
+impl Graph for BitGrid {
+    /// open the passage between two neighbouring cells
+    fn add_edge(&mut self, v1: usize, v2: usize) -> Result<()> {
+        let (v, passage) = self
+            .passage(v1, v2)
+            .ok_or_else(|| MazeError::of(ErrorKind::InvalidVertexOrVertices))?;
+
+        self.set(v, passage);
+        Ok(())
+    }
+
+    /// close the passage between two neighbouring cells
+    fn remove_edge(&mut self, v1: usize, v2: usize) -> Result<bool> {
+        let (v, passage) = self
+            .passage(v1, v2)
+            .ok_or_else(|| MazeError::of(ErrorKind::InvalidVertexOrVertices))?;
+
+        let open = self.is_set(v, passage);
+        self.clear(v, passage);
+        Ok(open)
+    }
+
+    /// iterate over the cells sharing an open passage with the given cell,
+    /// in increasing order
+    fn neighbours(&self, v: usize) -> Neighbours<'_> {
+        if v >= self.size() {
+            return Neighbours::from_cells([None; 4]);
+        }
+
+        let w = self.width;
+        let north = v.checked_sub(w).filter(|&u| self.is_set(u, SOUTH));
+        let west = Some(v)
+            .filter(|v| v % w != 0)
+            .map(|v| v - 1)
+            .filter(|&u| self.is_set(u, EAST));
+        let east = Some(v + 1).filter(|_| self.is_set(v, EAST));
+        let south = Some(v + w).filter(|_| self.is_set(v, SOUTH));
+
+        Neighbours::from_cells([north, west, east, south])
+    }
+
+    fn has_edge(&self, v1: usize, v2: usize) -> bool {
+        self.passage(v1, v2)
+            .map_or(false, |(v, passage)| self.is_set(v, passage))
+    }
+
+    fn edge_count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn get_spanning_tree(&self, source: usize, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        random_spanning_tree(self, source, rng)
+    }
+
+    fn get_minimum_spanning_tree(
+        &self,
+        source: usize,
+        weight: &mut dyn FnMut(usize, usize) -> f64,
+    ) -> Result<Box<dyn Graph>> {
+        minimum_spanning_tree(self, source, weight)
+    }
+
+    fn get_empty_copy(&self) -> Box<dyn Graph> {
+        Box::new(BitGrid::new(self.height, self.width))
+    }
+
+    fn size(&self) -> usize {
+        self.height * self.width
+    }
+
+    fn display(&self) {
+        for vertex in 0..self.size() {
+            print!("{} : ", vertex);
+            for neighbour in self.neighbours(vertex) {
+                print!("{} ", neighbour);
+            }
+            println!();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::ds::graphs::generators::{
+        is_spanning_tree, MazeGenerator, Prim, RecursiveBacktracker,
+    };
+    use crate::ds::graphs::{algo, AdjacencySet};
+
+    #[test]
+    fn test_grid_matches_adjacency_set() {
+        let (h, w) = (5, 7);
+        let bit_grid = BitGrid::grid(h, w);
+        let adjacency_set = AdjacencySet::grid(h, w);
+
+        for v in 0..h * w {
+            assert_eq!(
+                bit_grid.get_adjacent_vertices(v).unwrap(),
+                adjacency_set.get_adjacent_vertices(v).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_edge() {
+        let mut g = BitGrid::new(3, 3);
+
+        g.add_edge(4, 1).unwrap();
+        g.add_edge(4, 5).unwrap();
+
+        assert_eq!(g.get_adjacent_vertices(4).unwrap(), vec![1, 5]);
+        assert_eq!(g.neighbours(4).rev().collect::<Vec<_>>(), vec![5, 1]);
+        assert_eq!(g.get_adjacent_vertices(1).unwrap(), vec![4]);
+        assert_eq!(g.get_adjacent_vertices(5).unwrap(), vec![4]);
+        assert!(g.get_adjacent_vertices(3).unwrap().is_empty());
+        assert!(g.has_edge(1, 4));
+        assert!(!g.has_edge(3, 4));
+        assert_eq!(g.edge_count(), 2);
+
+        assert!(g.remove_edge(5, 4).unwrap());
+        assert!(!g.remove_edge(5, 4).unwrap());
+        assert_eq!(g.degree(4), 1);
+        assert_eq!(g.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_add_edge_wrapping_row_invalid() {
+        let mut g = BitGrid::new(3, 3);
+
+        assert!(g.add_edge(2, 3).is_err());
+        assert!(g.add_edge(8, 9).is_err());
+    }
+
+    #[test]
+    fn test_spanning_tree_is_bit_grid() {
+        let grid = BitGrid::grid(16, 9);
+
+        let tree = Prim::default()
+            .generate(&grid, &mut StdRng::seed_from_u64(5))
+            .unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+        assert_eq!(tree.get_empty_copy().size(), grid.size());
+    }
+
+    /// run with `cargo test --release -- --ignored --nocapture` to time the
+    /// generation and solution of a 5000x5000 maze. This works on the graph
+    /// alone, as a `Maze` of that size would hold and draw every cell.
+    #[test]
+    #[ignore]
+    fn test_large_maze() {
+        let (h, w) = (5000, 5000);
+        let start = Instant::now();
+
+        let grid = BitGrid::grid(h, w);
+        let backtracker = RecursiveBacktracker::default();
+        let tree = backtracker
+            .generate(&grid, &mut StdRng::seed_from_u64(1))
+            .unwrap();
+        println!("generated {}x{} in {:?}", h, w, start.elapsed());
+
+        // breadth-first search from the top-left to the bottom-right cell
+        let path = algo::bfs(tree.as_ref(), 0)
+            .unwrap()
+            .path_to(h * w - 1)
+            .unwrap();
+        let length = path.len();
+        println!("solved in {:?}, path of {} cells", start.elapsed(), length);
+
+        assert!(length >= h + w - 1);
+    }
+}
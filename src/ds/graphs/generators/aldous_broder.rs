@@ -0,0 +1,133 @@
+//! The Aldous-Broder Algorithm (random walks).
+
+use rand::{Rng, RngCore};
+
+use super::MazeGenerator;
+use crate::ds::graphs::{algo, Graph};
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// The Aldous-Broder Algorithm performs a random walk over the whole grid,
+/// and adds the edge used to enter each cell for the first time to the
+/// spanning tree. Like Wilson's Algorithm, it samples uniformly from all
+/// spanning trees, but the walk can be very long on large grids - and would
+/// never end on a disconnected grid, which is refused instead.
+///
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AldousBroder;
+
+impl AldousBroder {
+    /// generate a spanning tree of the given grid graph, and also return
+    /// the number of steps taken by the random walk
+    pub fn generate_with_steps(
+        &self,
+        grid: &dyn Graph,
+        rng: &mut dyn RngCore,
+    ) -> Result<(Box<dyn Graph>, usize)> {
+        let n = grid.size();
+        let mut spanning_tree = grid.get_empty_copy();
+        let mut steps = 0;
+
+        if n == 0 {
+            return Ok((spanning_tree, steps));
+        }
+        if algo::connected_components(grid).0 > 1 {
+            return Err(MazeError::of(ErrorKind::DisconnectedGraph));
+        }
+
+        let mut visited = vec![false; n];
+        let mut current = rng.gen_range(0..n);
+        visited[current] = true;
+        let mut unvisited = n - 1;
+
+        while unvisited > 0 {
+            // the grid is connected, so every cell has a neighbour
+            let degree = grid.degree(current);
+            debug_assert!(degree > 0);
+            let next = grid
+                .neighbours(current)
+                .nth(rng.gen_range(0..degree))
+                .unwrap();
+            steps += 1;
+
+            if !visited[next] {
+                visited[next] = true;
+                unvisited -= 1;
+                spanning_tree.add_edge(current, next)?;
+            }
+            current = next;
+        }
+
+        Ok((spanning_tree, steps))
+    }
+}
+
+impl MazeGenerator for AldousBroder {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        self.generate_with_steps(grid, rng).map(|(tree, _)| tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::{AdjacencySet, GraphType};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_aldous_broder_spanning_tree() {
+        let grid = AdjacencySet::grid(6, 6);
+
+        let (tree, steps) = AldousBroder
+            .generate_with_steps(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+        // every cell other than the first needs at least one step
+        assert!(steps >= grid.size() - 1);
+    }
+
+    #[test]
+    fn test_aldous_broder_steps_reproducible() {
+        let grid = AdjacencySet::grid(5, 4);
+
+        let (_, first) = AldousBroder
+            .generate_with_steps(&grid, &mut StdRng::seed_from_u64(42))
+            .unwrap();
+        let (_, second) = AldousBroder
+            .generate_with_steps(&grid, &mut StdRng::seed_from_u64(42))
+            .unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_aldous_broder_disconnected() {
+        // an isolated cell, and two separate passages
+        let mut split = AdjacencySet::new(4, GraphType::UNDIRECTED);
+        split.add_edge(0, 1).unwrap();
+        split.add_edge(2, 3).unwrap();
+
+        for grid in &[AdjacencySet::new(2, GraphType::UNDIRECTED), split] {
+            let err = AldousBroder
+                .generate(grid, &mut rand::thread_rng())
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::DisconnectedGraph);
+        }
+    }
+
+    #[test]
+    fn test_aldous_broder_single_cell() {
+        let grid = AdjacencySet::grid(1, 1);
+
+        let (tree, steps) = AldousBroder
+            .generate_with_steps(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+        assert_eq!(steps, 0);
+    }
+}
@@ -0,0 +1,115 @@
+//! Spanning arborescences - mazes of one-way passages.
+
+use rand::RngCore;
+
+use super::MazeGenerator;
+use crate::ds::graphs::{Graph, GraphType};
+use crate::ds::Tiling;
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// A random spanning arborescence of a directed grid, rooted at a given
+/// cell: every passage is a one-way door leading away from the root, so
+/// every cell can be reached from the root along exactly one path, and no
+/// passage can be walked back through.
+///
+/// The arborescence is grown like Prim's Algorithm, following a random arc
+/// out of the tree at each step. Cells which cannot be reached from the root
+/// are left out of the maze. An undirected grid has no one-way arcs to
+/// follow, so it is refused.
+///
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Arborescence {
+    root: usize,
+}
+
+impl Arborescence {
+    /// an arborescence rooted at the given cell
+    pub fn new(root: usize) -> Self {
+        Arborescence { root }
+    }
+
+    // getters
+
+    pub fn get_root(&self) -> usize {
+        self.root
+    }
+}
+
+impl MazeGenerator for Arborescence {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        if grid.get_kind() != GraphType::DIRECTED {
+            return Err(MazeError::of(ErrorKind::InvalidGraphType));
+        }
+
+        grid.get_spanning_tree(self.root, rng)
+    }
+
+    fn get_graph_type(&self) -> GraphType {
+        GraphType::DIRECTED
+    }
+
+    /// only the square grid is built with an arc each way between
+    /// neighbouring cells
+    fn supports_tiling(&self, tiling: Tiling) -> bool {
+        tiling == Tiling::Square
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_arborescence_one_way() {
+        let (h, w) = (7, 6);
+        let grid = AdjacencySet::directed_grid(h, w);
+        let root = 9;
+
+        let maze = Arborescence::new(root)
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert_eq!(maze.edge_count(), h * w - 1);
+
+        // every cell is reached from the root, and no passage leads back
+        let mut visited = vec![false; h * w];
+        let mut stack = vec![root];
+        visited[root] = true;
+        while let Some(v) = stack.pop() {
+            for u in maze.neighbours(v) {
+                assert!(!maze.has_edge(u, v));
+                assert!(!visited[u]);
+                visited[u] = true;
+                stack.push(u);
+            }
+        }
+        assert!(visited.iter().all(|&v| v));
+    }
+
+    #[test]
+    fn test_arborescence_invalid_root() {
+        let grid = AdjacencySet::directed_grid(2, 2);
+
+        let err = Arborescence::new(4)
+            .generate(&grid, &mut rand::thread_rng())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidVertexOrVertices);
+    }
+
+    #[test]
+    fn test_arborescence_undirected_grid() {
+        let grid = AdjacencySet::grid(2, 2);
+
+        let err = Arborescence::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidGraphType);
+
+        assert!(Arborescence::default().supports_tiling(Tiling::Square));
+        assert!(!Arborescence::default().supports_tiling(Tiling::Hex));
+    }
+}
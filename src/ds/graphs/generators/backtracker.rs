@@ -0,0 +1,133 @@
+//! The Recursive Backtracker (randomised depth-first search).
+
+use rand::{Rng, RngCore};
+
+use super::texture::choose_next;
+use super::{MazeGenerator, Texture};
+use crate::ds::graphs::Graph;
+use crate::error::Result;
+
+///
+/// Randomised depth-first search: carve a passage to a random unvisited
+/// neighbour of the current cell, and backtrack when there are none left.
+/// This produces long, winding corridors with few dead ends.
+///
+/// Despite the name, the search uses an explicit stack of cells instead of
+/// recursion, so the depth of the search is limited only by memory.
+///
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RecursiveBacktracker {
+    texture: Option<Texture>,
+}
+
+impl RecursiveBacktracker {
+    /// the recursive backtracker shaped by the given texture
+    pub fn with_texture(texture: Texture) -> Self {
+        RecursiveBacktracker {
+            texture: Some(texture),
+        }
+    }
+}
+
+impl MazeGenerator for RecursiveBacktracker {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        let n = grid.size();
+        let mut spanning_tree = grid.get_empty_copy();
+
+        if n == 0 {
+            return Ok(spanning_tree);
+        }
+
+        let mut visited = vec![false; n];
+        let start = rng.gen_range(0..n);
+        visited[start] = true;
+
+        // each cell on the stack, along with the cell it was entered from
+        let mut stack = vec![(start, None)];
+        // reused for the unvisited neighbours of each cell in turn
+        let mut unvisited = Vec::new();
+        while let Some(&(current, previous)) = stack.last() {
+            unvisited.clear();
+            unvisited.extend(
+                grid.neighbours(current)
+                    .filter(|&neighbour| !visited[neighbour]),
+            );
+
+            match choose_next(self.texture.as_ref(), previous, current, &unvisited, rng) {
+                Some(next) => {
+                    visited[next] = true;
+                    spanning_tree.add_edge(current, next)?;
+                    stack.push((next, Some(current)));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_backtracker_spanning_tree() {
+        let grid = AdjacencySet::grid(9, 8);
+
+        let tree = RecursiveBacktracker::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert_eq!(tree.size(), grid.size());
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_backtracker_straightness() {
+        let (h, w) = (12, 12);
+        let grid = AdjacencySet::grid(h, w);
+
+        let winding = RecursiveBacktracker::with_texture(Texture::new(w, 0.5, 0.0));
+        let straight = RecursiveBacktracker::with_texture(Texture::new(w, 0.5, 0.95));
+
+        let mut rng = StdRng::seed_from_u64(17);
+        let (mut winding_turns, mut straight_turns) = (0, 0);
+        for _ in 0..10 {
+            winding_turns += turns(winding.generate(&grid, &mut rng).unwrap().as_ref(), w);
+            straight_turns += turns(straight.generate(&grid, &mut rng).unwrap().as_ref(), w);
+        }
+
+        assert!(straight_turns < winding_turns);
+    }
+
+    /// count the cells where a horizontal passage meets a vertical one
+    fn turns(tree: &dyn Graph, w: usize) -> usize {
+        (0..tree.size())
+            .filter(|&v| {
+                let neighbours = tree.get_adjacent_vertices(v).unwrap();
+                let horizontal = neighbours.iter().any(|&u| u / w == v / w);
+                let vertical = neighbours.iter().any(|&u| u / w != v / w);
+                horizontal && vertical
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_backtracker_deep_search() {
+        // a single row forces the search to be as deep as the grid is wide
+        let grid = AdjacencySet::grid(1, 1500);
+
+        let tree = RecursiveBacktracker::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+}
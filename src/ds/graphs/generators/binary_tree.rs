@@ -0,0 +1,131 @@
+//! The Binary Tree Algorithm.
+
+use rand::{Rng, RngCore};
+
+use super::{Bias, MazeGenerator};
+use crate::ds::graphs::Graph;
+use crate::ds::Tiling;
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// The Binary Tree Algorithm carves a passage from every cell either
+/// vertically or horizontally, in the direction of its bias, by flipping
+/// a coin. It needs no state other than the current cell, which makes it
+/// extremely fast, but the mazes have two open corridors along the sides
+/// of the bias corner and a strong diagonal texture.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct BinaryTree {
+    height: usize,
+    width: usize,
+    bias: Bias,
+}
+
+impl BinaryTree {
+    /// create a generator for a maze with the given dimensions and bias
+    pub fn new(height: usize, width: usize, bias: Bias) -> Self {
+        BinaryTree {
+            height,
+            width,
+            bias,
+        }
+    }
+}
+
+impl MazeGenerator for BinaryTree {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        // the grid must match the dimensions of the generator
+        if grid.size() != self.height * self.width {
+            return Err(MazeError::of(ErrorKind::InvalidDimensions));
+        }
+
+        let (h, w) = (self.height, self.width);
+        let mut spanning_tree = grid.get_empty_copy();
+
+        for i in 0..h {
+            for j in 0..w {
+                let vertical = self.bias.vertical_neighbour(i, j, h, w);
+                let horizontal = self.bias.horizontal_neighbour(i, j, w);
+
+                let next = match (vertical, horizontal) {
+                    (Some(v), Some(h)) => Some(if rng.gen_bool(0.5) { v } else { h }),
+                    (v, h) => v.or(h),
+                };
+
+                if let Some(next) = next {
+                    spanning_tree.add_edge(w * i + j, next)?;
+                }
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+
+    /// every cell is joined to the cell directly above or below it, which
+    /// half of the cells of a triangular maze do not have
+    fn supports_tiling(&self, tiling: Tiling) -> bool {
+        matches!(tiling, Tiling::Square | Tiling::Hex)
+    }
+
+    /// the passages only lead along the rows and columns of a single level
+    fn supports_levels(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_binary_tree_spanning_tree() {
+        let grid = AdjacencySet::grid(7, 11);
+
+        for &bias in &[
+            Bias::NorthEast,
+            Bias::NorthWest,
+            Bias::SouthEast,
+            Bias::SouthWest,
+        ] {
+            let tree = BinaryTree::new(7, 11, bias)
+                .generate(&grid, &mut rand::thread_rng())
+                .unwrap();
+
+            assert_eq!(tree.size(), grid.size());
+            assert!(is_spanning_tree(tree.as_ref()));
+        }
+    }
+
+    #[test]
+    fn test_binary_tree_open_corridors() {
+        let grid = AdjacencySet::grid(4, 5);
+
+        let tree = BinaryTree::new(4, 5, Bias::SouthWest)
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        // the bottom row and the leftmost column are open corridors
+        for j in 0..4 {
+            assert!(tree
+                .get_adjacent_vertices(15 + j)
+                .unwrap()
+                .contains(&(16 + j)));
+        }
+        for i in 0..3 {
+            assert!(tree
+                .get_adjacent_vertices(5 * i)
+                .unwrap()
+                .contains(&(5 * i + 5)));
+        }
+    }
+
+    #[test]
+    fn test_binary_tree_wrong_dimensions() {
+        let grid = AdjacencySet::grid(3, 3);
+
+        let tree = BinaryTree::new(3, 4, Bias::default()).generate(&grid, &mut rand::thread_rng());
+        assert_eq!(tree.err().unwrap().kind(), ErrorKind::InvalidDimensions);
+    }
+}
@@ -0,0 +1,120 @@
+//! Braiding - removing dead ends from a maze.
+
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+use crate::ds::graphs::Graph;
+use crate::error::Result;
+
+///
+/// Remove (approximately) the given fraction of the dead ends of `maze` by
+/// knocking out one more wall of each, using the neighbouring cells from
+/// `grid`. This turns a perfect maze into a braided maze with cycles, so
+/// that there is no longer a unique path between every pair of cells.
+///
+/// Where possible, the wall knocked out leads to another dead end, which
+/// removes both dead ends at once. Returns the number of walls knocked out.
+///
+pub fn braid(
+    maze: &mut dyn Graph,
+    grid: &dyn Graph,
+    fraction: f64,
+    rng: &mut dyn RngCore,
+) -> Result<usize> {
+    let fraction = fraction.clamp(0.0, 1.0);
+
+    let mut dead_ends = (0..maze.size())
+        .filter(|&v| maze.degree(v) == 1)
+        .collect::<Vec<usize>>();
+    dead_ends.shuffle(rng);
+
+    let mut removed = 0;
+    for vertex in dead_ends {
+        // an earlier wall may already have been knocked out here
+        if maze.degree(vertex) != 1 || !rng.gen_bool(fraction) {
+            continue;
+        }
+
+        let walls = grid
+            .neighbours(vertex)
+            .filter(|&neighbour| !maze.has_edge(vertex, neighbour))
+            .collect::<Vec<usize>>();
+
+        let other_dead_ends = walls
+            .iter()
+            .cloned()
+            .filter(|&neighbour| maze.degree(neighbour) == 1)
+            .collect::<Vec<usize>>();
+
+        let candidates = if other_dead_ends.is_empty() {
+            walls
+        } else {
+            other_dead_ends
+        };
+
+        if let Some(&neighbour) = candidates.choose(rng) {
+            maze.add_edge(vertex, neighbour)?;
+            removed += 1;
+        }
+    }
+
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MazeGenerator, RecursiveBacktracker};
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn dead_ends(maze: &dyn Graph) -> usize {
+        (0..maze.size()).filter(|&v| maze.degree(v) == 1).count()
+    }
+
+    #[test]
+    fn test_braid_all_dead_ends() {
+        let grid = AdjacencySet::grid(8, 8);
+        let mut rng = rand::thread_rng();
+        let mut maze = RecursiveBacktracker::default()
+            .generate(&grid, &mut rng)
+            .unwrap();
+
+        let removed = braid(maze.as_mut(), &grid, 1.0, &mut rng).unwrap();
+
+        assert!(removed > 0);
+        assert_eq!(dead_ends(maze.as_ref()), 0);
+        assert_eq!(maze.edge_count(), grid.size() - 1 + removed);
+    }
+
+    #[test]
+    fn test_braid_no_dead_ends() {
+        let grid = AdjacencySet::grid(8, 8);
+        let mut rng = rand::thread_rng();
+        let mut maze = RecursiveBacktracker::default()
+            .generate(&grid, &mut rng)
+            .unwrap();
+        let before = dead_ends(maze.as_ref());
+
+        let removed = braid(maze.as_mut(), &grid, 0.0, &mut rng).unwrap();
+
+        assert_eq!(removed, 0);
+        assert_eq!(dead_ends(maze.as_ref()), before);
+    }
+
+    #[test]
+    fn test_braid_partial() {
+        let grid = AdjacencySet::grid(20, 20);
+        let mut rng = StdRng::seed_from_u64(12);
+        let mut maze = RecursiveBacktracker::default()
+            .generate(&grid, &mut rng)
+            .unwrap();
+        let before = dead_ends(maze.as_ref());
+
+        braid(maze.as_mut(), &grid, 0.5, &mut rng).unwrap();
+
+        let after = dead_ends(maze.as_ref());
+        assert!(after < before);
+    }
+}
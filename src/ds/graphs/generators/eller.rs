@@ -0,0 +1,297 @@
+//! Eller's Algorithm (row-by-row generation).
+
+use rand::{Rng, RngCore};
+
+use super::MazeGenerator;
+use crate::ds::disjoint_set::DisjointSet;
+use crate::ds::graphs::Graph;
+use crate::ds::Tiling;
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// Eller's Algorithm generates the maze one row at a time, remembering only
+/// which cells of the current row are already connected to each other. This
+/// needs O(width) memory regardless of the height of the maze, so the rows
+/// can be streamed through `Eller::rows` and rendered or exported as they
+/// are produced.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct Eller {
+    height: usize,
+    width: usize,
+}
+
+impl Eller {
+    /// create a generator for a maze with the given dimensions
+    pub fn new(height: usize, width: usize) -> Self {
+        Eller { height, width }
+    }
+
+    /// stream the rows of a maze of the given width which never ends
+    pub fn rows<R: Rng>(width: usize, rng: R) -> EllerRows<R> {
+        EllerRows::new(None, width, rng)
+    }
+
+    /// stream the rows of a maze with the given dimensions - the last row
+    /// connects every cell which is still disconnected from the rest
+    pub fn rows_with_height<R: Rng>(height: usize, width: usize, rng: R) -> EllerRows<R> {
+        EllerRows::new(Some(height), width, rng)
+    }
+}
+
+impl MazeGenerator for Eller {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        // the grid must match the dimensions of the generator
+        if grid.size() != self.height * self.width {
+            return Err(MazeError::of(ErrorKind::InvalidDimensions));
+        }
+
+        let w = self.width;
+        let mut spanning_tree = grid.get_empty_copy();
+
+        for (i, row) in Eller::rows_with_height(self.height, w, rng).enumerate() {
+            for j in 0..w {
+                if row.has_east_passage(j) {
+                    spanning_tree.add_edge(w * i + j, w * i + j + 1)?;
+                }
+                if row.has_south_passage(j) {
+                    spanning_tree.add_edge(w * i + j, w * i + j + w)?;
+                }
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+
+    /// the sets of one row are carried down through the cells directly
+    /// below, which only triangles pointing up have
+    fn supports_tiling(&self, tiling: Tiling) -> bool {
+        matches!(tiling, Tiling::Square | Tiling::Hex)
+    }
+
+    /// the rows are carved one after another down a single level
+    fn supports_levels(&self) -> bool {
+        false
+    }
+}
+
+///
+/// A single row of a maze generated by Eller's Algorithm, described by
+/// the passages leading out of each cell to the east and to the south
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EllerRow {
+    east: Vec<bool>,
+    south: Vec<bool>,
+}
+
+impl EllerRow {
+    /// the number of cells in the row
+    pub fn width(&self) -> usize {
+        self.south.len()
+    }
+
+    /// whether there is a passage from cell `j` to its eastern neighbour
+    pub fn has_east_passage(&self, j: usize) -> bool {
+        j + 1 < self.width() && self.east[j]
+    }
+
+    /// whether there is a passage from cell `j` to the cell below it
+    pub fn has_south_passage(&self, j: usize) -> bool {
+        self.south[j]
+    }
+
+    /// the top border of a maze of the given width, using the same
+    /// sprites as the terminal renderer
+    pub fn render_top(width: usize) -> String {
+        let mut line = String::from("+");
+        for _ in 0..width {
+            line.push_str("---+");
+        }
+        line
+    }
+
+    /// render this row as two lines of text - the cells with their east
+    /// walls, followed by their south walls
+    pub fn render(&self) -> String {
+        let mut cells = String::from("|");
+        let mut walls = String::from("+");
+
+        for j in 0..self.width() {
+            let east_wall = if self.has_east_passage(j) { ' ' } else { '|' };
+            let south_wall = if self.has_south_passage(j) {
+                "   "
+            } else {
+                "---"
+            };
+
+            cells.push_str("   ");
+            cells.push(east_wall);
+            walls.push_str(south_wall);
+            walls.push('+');
+        }
+
+        format!("{}\n{}", cells, walls)
+    }
+}
+
+///
+/// Iterator over the rows of a maze generated by Eller's Algorithm. Only
+/// the set memberships of the current row are kept between rows.
+///
+pub struct EllerRows<R> {
+    width: usize,
+    // the number of rows still to be produced, if the maze has an end
+    remaining: Option<usize>,
+    // the set that each cell of the next row belongs to
+    sets: Vec<usize>,
+    rng: R,
+}
+
+impl<R: Rng> EllerRows<R> {
+    fn new(height: Option<usize>, width: usize, rng: R) -> Self {
+        EllerRows {
+            width,
+            remaining: height,
+            sets: (0..width).collect(),
+            rng,
+        }
+    }
+
+    /// produce the next row, which is the last row of the maze if `last`
+    /// is set
+    fn next_row(&mut self, last: bool) -> EllerRow {
+        let w = self.width;
+        let mut components = DisjointSet::new(w);
+
+        // randomly join adjacent cells in different sets - the last row
+        // joins all of them so that the whole maze is connected
+        let mut east = vec![false; w.saturating_sub(1)];
+        for (j, passage) in east.iter_mut().enumerate() {
+            if !components.connected(self.sets[j], self.sets[j + 1])
+                && (last || self.rng.gen_bool(0.5))
+            {
+                components.union(self.sets[j], self.sets[j + 1]);
+                *passage = true;
+            }
+        }
+
+        let mut south = vec![false; w];
+        if last {
+            return EllerRow { east, south };
+        }
+
+        // every set must extend downwards at least once, so that none of
+        // them are cut off from the rest of the maze
+        let mut members = vec![Vec::new(); w];
+        for j in 0..w {
+            members[components.find(self.sets[j])].push(j);
+        }
+
+        for cells in members.iter().filter(|cells| !cells.is_empty()) {
+            for &j in cells {
+                south[j] = self.rng.gen_bool(0.5);
+            }
+            if !cells.iter().any(|&j| south[j]) {
+                south[cells[self.rng.gen_range(0..cells.len())]] = true;
+            }
+        }
+
+        // cells below a passage stay in the set above them, and the rest
+        // start sets of their own. Renumbering the sets keeps them in 0..w.
+        let mut renumbered = vec![None; 2 * w];
+        let mut count = 0;
+        for (j, &passage) in south.iter().enumerate() {
+            let set = if passage {
+                components.find(self.sets[j])
+            } else {
+                w + j
+            };
+
+            self.sets[j] = *renumbered[set].get_or_insert_with(|| {
+                count += 1;
+                count - 1
+            });
+        }
+
+        EllerRow { east, south }
+    }
+}
+
+impl<R: Rng> Iterator for EllerRows<R> {
+    type Item = EllerRow;
+
+    fn next(&mut self) -> Option<EllerRow> {
+        let last = match self.remaining {
+            Some(0) => return None,
+            Some(ref mut remaining) => {
+                *remaining -= 1;
+                *remaining == 0
+            }
+            None => false,
+        };
+
+        Some(self.next_row(last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_eller_spanning_tree() {
+        let grid = AdjacencySet::grid(10, 7);
+
+        let tree = Eller::new(10, 7)
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert_eq!(tree.size(), grid.size());
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_eller_single_row() {
+        let mut rows = Eller::rows_with_height(1, 5, rand::thread_rng());
+
+        let row = rows.next().unwrap();
+        for j in 0..4 {
+            assert!(row.has_east_passage(j));
+        }
+        assert!(!row.has_east_passage(4));
+        assert!(rows.next().is_none());
+    }
+
+    #[test]
+    fn test_eller_endless_rows() {
+        let rows = Eller::rows(12, rand::thread_rng());
+
+        for row in rows.take(10_000) {
+            assert_eq!(row.width(), 12);
+            // only the last row can have no passages leading south
+            assert!((0..12).any(|j| row.has_south_passage(j)));
+        }
+    }
+
+    #[test]
+    fn test_eller_render() {
+        let row = EllerRow {
+            east: vec![true, false],
+            south: vec![false, true, false],
+        };
+
+        assert_eq!(EllerRow::render_top(3), "+---+---+---+");
+        assert_eq!(row.render(), "|       |   |\n+---+   +---+");
+    }
+
+    #[test]
+    fn test_eller_wrong_dimensions() {
+        let grid = AdjacencySet::grid(3, 3);
+
+        let tree = Eller::new(3, 4).generate(&grid, &mut rand::thread_rng());
+        assert_eq!(tree.err().unwrap().kind(), ErrorKind::InvalidDimensions);
+    }
+}
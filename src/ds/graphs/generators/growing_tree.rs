@@ -0,0 +1,258 @@
+//! The Growing Tree Algorithm.
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+use super::texture::choose_next;
+use super::{MazeGenerator, Texture};
+use crate::ds::graphs::Graph;
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// The policy used by the Growing Tree Algorithm to pick the next cell
+/// to grow the maze from, out of the cells which may still have
+/// unvisited neighbours
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionPolicy {
+    /// the most recently added cell - this behaves like the recursive backtracker
+    Newest,
+    /// the least recently added cell
+    Oldest,
+    /// any cell at random - this behaves like Prim's Algorithm
+    Random,
+    /// one of the given policies, chosen at random in proportion to its weight
+    Mixed(Vec<(SelectionPolicy, u32)>),
+}
+
+impl SelectionPolicy {
+    /// pick the index of the next cell out of `len` active cells
+    fn select(&self, len: usize, rng: &mut dyn RngCore) -> usize {
+        match self {
+            SelectionPolicy::Newest => len - 1,
+            SelectionPolicy::Oldest => 0,
+            SelectionPolicy::Random => rng.gen_range(0..len),
+            SelectionPolicy::Mixed(policies) => policies
+                .choose_weighted(rng, |&(_, weight)| weight)
+                .map(|(policy, _)| policy)
+                .unwrap_or(&SelectionPolicy::Newest)
+                .select(len, rng),
+        }
+    }
+
+    /// whether the cells may be picked in any order, so that the order of
+    /// the active cells need not be kept
+    fn is_unordered(&self) -> bool {
+        match self {
+            SelectionPolicy::Random => true,
+            SelectionPolicy::Mixed(policies) => {
+                policies.iter().all(|(policy, _)| policy.is_unordered())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for SelectionPolicy {
+    type Err = MazeError;
+
+    /// parse a policy of the form `newest`, `oldest`, `random`, or a weighted
+    /// mix of these such as `newest=75,random=25`
+    fn from_str(s: &str) -> Result<Self> {
+        let simple = |name: &str| match name.trim() {
+            "newest" => Ok(SelectionPolicy::Newest),
+            "oldest" => Ok(SelectionPolicy::Oldest),
+            "random" => Ok(SelectionPolicy::Random),
+            _ => Err(MazeError::of(ErrorKind::InvalidGenerator)),
+        };
+
+        if !s.contains('=') {
+            return simple(s);
+        }
+
+        let mut policies = Vec::new();
+        for part in s.split(',') {
+            let mut pieces = part.splitn(2, '=');
+            let policy = simple(pieces.next().unwrap_or(""))?;
+            let weight = pieces
+                .next()
+                .and_then(|weight| u32::from_str(weight.trim()).ok())
+                .ok_or_else(|| MazeError::of(ErrorKind::InvalidGenerator))?;
+
+            policies.push((policy, weight));
+        }
+
+        if policies.iter().all(|&(_, weight)| weight == 0) {
+            return Err(MazeError::of(ErrorKind::InvalidGenerator));
+        }
+
+        Ok(SelectionPolicy::Mixed(policies))
+    }
+}
+
+///
+/// The Growing Tree Algorithm keeps a list of active cells, and repeatedly
+/// picks one of them (according to its selection policy) to carve a passage
+/// to a random unvisited neighbour. Cells without unvisited neighbours are
+/// removed from the list. Depending on the policy, the mazes range from
+/// those of the recursive backtracker to those of Prim's Algorithm.
+///
+#[derive(Debug, Clone)]
+pub struct GrowingTree {
+    policy: SelectionPolicy,
+    texture: Option<Texture>,
+}
+
+impl GrowingTree {
+    pub fn new(policy: SelectionPolicy) -> Self {
+        GrowingTree {
+            policy,
+            texture: None,
+        }
+    }
+
+    /// the Growing Tree Algorithm shaped by the given texture
+    pub fn with_texture(policy: SelectionPolicy, texture: Texture) -> Self {
+        GrowingTree {
+            policy,
+            texture: Some(texture),
+        }
+    }
+
+    // getters
+    pub fn get_policy(&self) -> &SelectionPolicy {
+        &self.policy
+    }
+}
+
+impl Default for GrowingTree {
+    fn default() -> Self {
+        GrowingTree::new(SelectionPolicy::Newest)
+    }
+}
+
+impl MazeGenerator for GrowingTree {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        let n = grid.size();
+        let mut spanning_tree = grid.get_empty_copy();
+
+        if n == 0 {
+            return Ok(spanning_tree);
+        }
+
+        let mut visited = vec![false; n];
+        let start = rng.gen_range(0..n);
+        visited[start] = true;
+
+        // each active cell, along with the cell it was entered from
+        let mut active = VecDeque::new();
+        active.push_back((start, None));
+        // reused for the unvisited neighbours of each cell in turn
+        let mut unvisited = Vec::new();
+
+        while !active.is_empty() {
+            let index = self.policy.select(active.len(), rng);
+            let (current, previous) = active[index];
+
+            unvisited.clear();
+            unvisited.extend(
+                grid.neighbours(current)
+                    .filter(|&neighbour| !visited[neighbour]),
+            );
+
+            match choose_next(self.texture.as_ref(), previous, current, &unvisited, rng) {
+                Some(next) => {
+                    visited[next] = true;
+                    spanning_tree.add_edge(current, next)?;
+                    active.push_back((next, Some(current)));
+                }
+                // moving the last cell into the gap is quicker than shifting
+                // the cells along, when their order does not matter
+                None if self.policy.is_unordered() => {
+                    active.swap_remove_back(index);
+                }
+                None => {
+                    active.remove(index);
+                }
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_growing_tree_spanning_tree() {
+        let grid = AdjacencySet::grid(8, 8);
+
+        for policy in [
+            SelectionPolicy::Newest,
+            SelectionPolicy::Oldest,
+            SelectionPolicy::Random,
+            SelectionPolicy::Mixed(vec![
+                (SelectionPolicy::Newest, 75),
+                (SelectionPolicy::Random, 25),
+            ]),
+        ] {
+            let tree = GrowingTree::new(policy)
+                .generate(&grid, &mut rand::thread_rng())
+                .unwrap();
+
+            assert_eq!(tree.size(), grid.size());
+            assert!(is_spanning_tree(tree.as_ref()));
+        }
+    }
+
+    #[test]
+    fn test_growing_tree_textured_spanning_tree() {
+        let grid = AdjacencySet::grid(8, 8);
+
+        let generator =
+            GrowingTree::with_texture(SelectionPolicy::Newest, Texture::new(8, 0.9, 0.5));
+        let tree = generator.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_parse_selection_policy() {
+        assert_eq!(
+            SelectionPolicy::from_str("oldest").unwrap(),
+            SelectionPolicy::Oldest
+        );
+        assert_eq!(
+            SelectionPolicy::from_str("newest=75,random=25").unwrap(),
+            SelectionPolicy::Mixed(vec![
+                (SelectionPolicy::Newest, 75),
+                (SelectionPolicy::Random, 25),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_selection_policy_unordered() {
+        assert!(SelectionPolicy::Random.is_unordered());
+        assert!(!SelectionPolicy::Newest.is_unordered());
+
+        let random = SelectionPolicy::from_str("random=1,random=2").unwrap();
+        assert!(random.is_unordered());
+        let mixed = SelectionPolicy::from_str("newest=75,random=25").unwrap();
+        assert!(!mixed.is_unordered());
+    }
+
+    #[test]
+    fn test_parse_selection_policy_invalid() {
+        assert!(SelectionPolicy::from_str("latest").is_err());
+        assert!(SelectionPolicy::from_str("newest=x").is_err());
+        assert!(SelectionPolicy::from_str("newest=0,random=0").is_err());
+    }
+}
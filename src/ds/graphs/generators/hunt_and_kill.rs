@@ -0,0 +1,145 @@
+//! The Hunt-and-Kill Algorithm.
+
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+use super::texture::choose_next;
+use super::{MazeGenerator, Texture};
+use crate::ds::graphs::Graph;
+use crate::error::Result;
+
+///
+/// The Hunt-and-Kill Algorithm performs a random walk to unvisited cells
+/// (the "kill" phase) until it gets stuck, and then scans the cells in order
+/// for an unvisited cell next to a visited one (the "hunt" phase), which is
+/// joined to the maze to start the next walk. This produces long winding
+/// corridors with few dead ends, like the recursive backtracker, but without
+/// having to remember the path taken.
+///
+#[derive(Debug, Copy, Clone, Default)]
+pub struct HuntAndKill {
+    texture: Option<Texture>,
+}
+
+impl HuntAndKill {
+    /// Hunt-and-Kill shaped by the given texture
+    pub fn with_texture(texture: Texture) -> Self {
+        HuntAndKill {
+            texture: Some(texture),
+        }
+    }
+}
+
+impl MazeGenerator for HuntAndKill {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        let n = grid.size();
+        let mut spanning_tree = grid.get_empty_copy();
+
+        if n == 0 {
+            return Ok(spanning_tree);
+        }
+
+        let mut visited = vec![false; n];
+        let mut current = Some(rng.gen_range(0..n));
+        let mut previous = None;
+        // every cell before this one is known to have been visited
+        let mut hunt_start = 0;
+        // reused for the unvisited neighbours of each cell in turn
+        let mut unvisited = Vec::new();
+
+        while let Some(vertex) = current {
+            visited[vertex] = true;
+
+            unvisited.clear();
+            unvisited.extend(
+                grid.neighbours(vertex)
+                    .filter(|&neighbour| !visited[neighbour]),
+            );
+
+            let next = match choose_next(self.texture.as_ref(), previous, vertex, &unvisited, rng) {
+                Some(next) => {
+                    spanning_tree.add_edge(vertex, next)?;
+                    Some((next, vertex))
+                }
+                None => {
+                    while hunt_start < n && visited[hunt_start] {
+                        hunt_start += 1;
+                    }
+                    hunt(grid, &visited, hunt_start, spanning_tree.as_mut(), rng)?
+                }
+            };
+
+            current = next.map(|(next, _)| next);
+            previous = next.map(|(_, previous)| previous);
+        }
+
+        Ok(spanning_tree)
+    }
+}
+
+/// find the first unvisited cell (from `start` onwards) which is next to a
+/// visited cell, and join it to a random one of its visited neighbours.
+/// Returns the cell along with the neighbour it was joined to.
+fn hunt(
+    grid: &dyn Graph,
+    visited: &[bool],
+    start: usize,
+    spanning_tree: &mut dyn Graph,
+    rng: &mut dyn RngCore,
+) -> Result<Option<(usize, usize)>> {
+    let mut visited_neighbours = Vec::new();
+    for vertex in (start..grid.size()).filter(|&v| !visited[v]) {
+        visited_neighbours.clear();
+        visited_neighbours.extend(
+            grid.neighbours(vertex)
+                .filter(|&neighbour| visited[neighbour]),
+        );
+
+        if let Some(&neighbour) = visited_neighbours.choose(rng) {
+            spanning_tree.add_edge(vertex, neighbour)?;
+            return Ok(Some((vertex, neighbour)));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_hunt_and_kill_spanning_tree() {
+        let grid = AdjacencySet::grid(10, 12);
+
+        let tree = HuntAndKill::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert_eq!(tree.size(), grid.size());
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_hunt_and_kill_textured_spanning_tree() {
+        let grid = AdjacencySet::grid(10, 12);
+
+        let generator = HuntAndKill::with_texture(Texture::new(12, 0.2, 0.7));
+        let tree = generator.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_hunt_and_kill_single_column() {
+        let grid = AdjacencySet::grid(6, 1);
+
+        let tree = HuntAndKill::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+}
@@ -0,0 +1,78 @@
+//! Randomised variant of Kruskal's Algorithm.
+
+use rand::seq::SliceRandom;
+use rand::RngCore;
+
+use super::MazeGenerator;
+use crate::ds::disjoint_set::DisjointSet;
+use crate::ds::graphs::Graph;
+use crate::error::Result;
+
+///
+/// Kruskal's Algorithm over the edges of the grid in a random order. Every
+/// edge joining two cells which are not yet connected becomes a passage,
+/// which produces mazes with many short dead ends.
+///
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Kruskal;
+
+impl MazeGenerator for Kruskal {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        let n = grid.size();
+
+        // each undirected edge is collected once, from its lower vertex
+        let mut edges = Vec::new();
+        for v in 0..n {
+            for neighbour in grid.neighbours(v) {
+                if v < neighbour {
+                    edges.push((v, neighbour));
+                }
+            }
+        }
+        edges.shuffle(rng);
+
+        let mut components = DisjointSet::new(n);
+        let mut spanning_tree = grid.get_empty_copy();
+
+        for (from, to) in edges {
+            if components.union(from, to) {
+                spanning_tree.add_edge(from, to)?;
+
+                if components.count() == 1 {
+                    break;
+                }
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_kruskal_spanning_tree() {
+        let grid = AdjacencySet::grid(8, 5);
+
+        let tree = Kruskal.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+        assert_eq!(tree.size(), grid.size());
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_kruskal_single_row() {
+        let grid = AdjacencySet::grid(1, 10);
+
+        let tree = Kruskal.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+        // a single row has exactly one spanning tree
+        for v in 0..9 {
+            assert!(tree.has_edge(v, v + 1));
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! This submodule contains the maze generation algorithms. Each algorithm
+//! takes the grid graph of the maze (every cell connected to its neighbours)
+//! and produces a spanning tree of that graph - the passages of the maze.
+
+use std::str::FromStr;
+
+use rand::RngCore;
+
+use super::{Graph, GraphType};
+use crate::ds::Tiling;
+use crate::error::{ErrorKind, MazeError, Result};
+
+mod aldous_broder;
+mod arborescence;
+mod backtracker;
+mod binary_tree;
+mod braid;
+mod eller;
+mod growing_tree;
+mod hunt_and_kill;
+mod kruskal;
+mod prim;
+mod recursive_division;
+mod sidewinder;
+mod texture;
+mod wilson;
+
+pub use self::aldous_broder::AldousBroder;
+pub use self::arborescence::Arborescence;
+pub use self::backtracker::RecursiveBacktracker;
+pub use self::binary_tree::BinaryTree;
+pub use self::braid::braid;
+pub use self::eller::{Eller, EllerRow, EllerRows};
+pub use self::growing_tree::{GrowingTree, SelectionPolicy};
+pub use self::hunt_and_kill::HuntAndKill;
+pub use self::kruskal::Kruskal;
+pub use self::prim::{EdgeWeights, Prim};
+pub use self::recursive_division::RecursiveDivision;
+pub use self::sidewinder::Sidewinder;
+pub use self::texture::Texture;
+pub use self::wilson::Wilson;
+
+///
+/// this trait defines the essential behaviour of a maze
+/// generation algorithm
+///
+pub trait MazeGenerator {
+    /// generate a spanning tree of the given grid graph, drawing
+    /// any random choices from `rng`
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>>;
+
+    /// the type of grid graph this generator expects - directed grids have
+    /// an arc each way between neighbouring cells
+    fn get_graph_type(&self) -> GraphType {
+        GraphType::UNDIRECTED
+    }
+
+    /// whether this generator can carve a maze whose cells have the shape of
+    /// the given tiling - generators which only look at the grid graph can
+    /// carve any shape of cell
+    fn supports_tiling(&self, _tiling: Tiling) -> bool {
+        true
+    }
+
+    /// whether this generator can carve a maze of several levels, stacked
+    /// one above the other - again, any generator which only looks at the
+    /// grid graph can
+    fn supports_levels(&self) -> bool {
+        true
+    }
+}
+
+///
+/// The corner of the maze that the passages of the row-wise generators
+/// (Binary Tree and Sidewinder) lead towards. For instance, with a
+/// `NorthEast` bias every cell has a passage either to its north or to its
+/// east, so that the top row and the rightmost column are open corridors.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Bias {
+    #[default]
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Bias {
+    fn is_north(self) -> bool {
+        self == Bias::NorthEast || self == Bias::NorthWest
+    }
+
+    fn is_east(self) -> bool {
+        self == Bias::NorthEast || self == Bias::SouthEast
+    }
+
+    /// the neighbour of the cell at row `i` and column `j` in the vertical
+    /// direction of the bias, in a maze with the given dimensions
+    fn vertical_neighbour(self, i: usize, j: usize, height: usize, width: usize) -> Option<usize> {
+        if self.is_north() && i > 0 {
+            Some(width * (i - 1) + j)
+        } else if !self.is_north() && i + 1 < height {
+            Some(width * (i + 1) + j)
+        } else {
+            None
+        }
+    }
+
+    /// the neighbour of the cell at row `i` and column `j` in the horizontal
+    /// direction of the bias, in a maze with the given width
+    fn horizontal_neighbour(self, i: usize, j: usize, width: usize) -> Option<usize> {
+        if self.is_east() && j + 1 < width {
+            Some(width * i + j + 1)
+        } else if !self.is_east() && j > 0 {
+            Some(width * i + j - 1)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Bias {
+    type Err = MazeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "ne" => Ok(Bias::NorthEast),
+            "nw" => Ok(Bias::NorthWest),
+            "se" => Ok(Bias::SouthEast),
+            "sw" => Ok(Bias::SouthWest),
+            _ => Err(MazeError::of(ErrorKind::InvalidGenerator)),
+        }
+    }
+}
+
+/// check that `tree` is a spanning tree of its vertices - it must have
+/// exactly n - 1 edges, and every vertex must be reachable from vertex 0
+#[cfg(test)]
+pub(crate) fn is_spanning_tree(tree: &dyn Graph) -> bool {
+    let n = tree.size();
+
+    let mut visited = vec![false; n];
+    let mut stack = vec![0];
+    visited[0] = true;
+
+    while let Some(v) = stack.pop() {
+        for neighbour in tree.neighbours(v) {
+            if !visited[neighbour] {
+                visited[neighbour] = true;
+                stack.push(neighbour);
+            }
+        }
+    }
+
+    tree.edge_count() == n - 1 && visited.iter().all(|&v| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generators_reproducible() {
+        let (h, w) = (5, 6);
+        let grid = AdjacencySet::grid(h, w);
+
+        let generators: Vec<Box<dyn MazeGenerator>> = vec![
+            Box::new(Prim::default()),
+            Box::new(Prim::with_texture(Texture::new(w, 0.7, 0.5))),
+            Box::new(Kruskal),
+            Box::new(Wilson),
+            Box::new(AldousBroder),
+            Box::new(RecursiveBacktracker::default()),
+            Box::new(HuntAndKill::with_texture(Texture::new(w, 0.3, 0.8))),
+            Box::new(GrowingTree::new(SelectionPolicy::Random)),
+            Box::new(RecursiveDivision::new(h, w)),
+            Box::new(Eller::new(h, w)),
+            Box::new(BinaryTree::new(h, w, Bias::NorthEast)),
+            Box::new(Sidewinder::new(h, w, Bias::SouthWest)),
+        ];
+
+        for generator in generators {
+            let first = generator
+                .generate(&grid, &mut StdRng::seed_from_u64(1234))
+                .unwrap();
+            let second = generator
+                .generate(&grid, &mut StdRng::seed_from_u64(1234))
+                .unwrap();
+
+            for v in 0..grid.size() {
+                assert_eq!(
+                    first.get_adjacent_vertices(v).unwrap(),
+                    second.get_adjacent_vertices(v).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_generators_empty_grid() {
+        let grid = AdjacencySet::grid(0, 0);
+
+        let generators: Vec<Box<dyn MazeGenerator>> = vec![
+            Box::new(Prim::default()),
+            Box::new(Prim::with_weights(|_, _| 1.0)),
+            Box::new(Prim::with_texture(Texture::new(0, 0.7, 0.5))),
+            Box::new(Kruskal),
+            Box::new(Wilson),
+            Box::new(AldousBroder),
+            Box::new(RecursiveBacktracker::default()),
+            Box::new(HuntAndKill::default()),
+            Box::new(GrowingTree::new(SelectionPolicy::Random)),
+            Box::new(RecursiveDivision::new(0, 0)),
+            Box::new(Eller::new(0, 0)),
+            Box::new(BinaryTree::new(0, 0, Bias::NorthEast)),
+            Box::new(Sidewinder::new(0, 0, Bias::SouthWest)),
+        ];
+
+        for generator in generators {
+            let tree = generator.generate(&grid, &mut rand::thread_rng()).unwrap();
+            assert_eq!(tree.size(), 0);
+        }
+
+        let tree = Arborescence::default()
+            .generate(&AdjacencySet::directed_grid(0, 0), &mut rand::thread_rng())
+            .unwrap();
+        assert_eq!(tree.size(), 0);
+    }
+
+    #[test]
+    fn test_bias_neighbours() {
+        assert_eq!(Bias::NorthEast.vertical_neighbour(0, 1, 3, 3), None);
+        assert_eq!(Bias::NorthEast.vertical_neighbour(1, 1, 3, 3), Some(1));
+        assert_eq!(Bias::SouthWest.vertical_neighbour(1, 1, 3, 3), Some(7));
+        assert_eq!(Bias::SouthWest.vertical_neighbour(2, 1, 3, 3), None);
+
+        assert_eq!(Bias::SouthEast.horizontal_neighbour(1, 1, 3), Some(5));
+        assert_eq!(Bias::SouthEast.horizontal_neighbour(1, 2, 3), None);
+        assert_eq!(Bias::NorthWest.horizontal_neighbour(1, 1, 3), Some(3));
+        assert_eq!(Bias::NorthWest.horizontal_neighbour(1, 0, 3), None);
+    }
+
+    #[test]
+    fn test_parse_bias() {
+        assert_eq!(Bias::from_str("se").unwrap(), Bias::SouthEast);
+        assert!(Bias::from_str("north").is_err());
+    }
+}
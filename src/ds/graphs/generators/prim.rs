@@ -0,0 +1,225 @@
+//! Randomised variant of Prim's Algorithm.
+
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::rc::Rc;
+
+use rand::{Rng, RngCore};
+
+use super::{MazeGenerator, Texture};
+use crate::ds::graphs::Graph;
+use crate::error::Result;
+
+/// the weight of the edge between two cells
+pub type EdgeWeights = Rc<dyn Fn(usize, usize) -> f64>;
+
+///
+/// Prim's Algorithm, starting from the top-left cell of the maze. By default,
+/// this is the spanning tree produced by `Graph::get_spanning_tree`, where
+/// every edge is given a random weight.
+///
+/// The caller may supply the edge weights instead, such as the difference in
+/// height between neighbouring cells of a height map, in which case the maze
+/// is the minimum spanning tree under those weights and no random choices are
+/// made.
+///
+/// With a texture, each edge leading out of the tree is instead given a
+/// random key weighted by the texture, and the edge with the highest key is
+/// added next. Passages continuing straight on are favoured by a factor of
+/// `1 / (1 - straightness)`, or not at all if the texture has no straightness.
+///
+#[derive(Clone, Default)]
+pub struct Prim {
+    texture: Option<Texture>,
+    weights: Option<EdgeWeights>,
+}
+
+impl Prim {
+    /// Prim's Algorithm shaped by the given texture
+    pub fn with_texture(texture: Texture) -> Self {
+        Prim {
+            texture: Some(texture),
+            weights: None,
+        }
+    }
+
+    /// Prim's Algorithm over the given edge weights, lightest first
+    pub fn with_weights<F>(weights: F) -> Self
+    where
+        F: Fn(usize, usize) -> f64 + 'static,
+    {
+        Prim {
+            texture: None,
+            weights: Some(Rc::new(weights)),
+        }
+    }
+
+    fn generate_textured(
+        &self,
+        texture: &Texture,
+        grid: &dyn Graph,
+        rng: &mut dyn RngCore,
+    ) -> Result<Box<dyn Graph>> {
+        let n = grid.size();
+        let mut spanning_tree = grid.get_empty_copy();
+
+        if n == 0 {
+            return Ok(spanning_tree);
+        }
+
+        // keep clear of an infinite weight for straight passages
+        let straightness = texture.get_straightness().unwrap_or(0.0);
+        let straight_weight = 1.0 / (1.0 - straightness.min(0.99));
+
+        let mut parent = vec![None; n];
+        let mut in_tree = vec![false; n];
+        let mut frontier = BinaryHeap::new();
+
+        let mut push_frontier =
+            |vertex: usize,
+             parent: &[Option<usize>],
+             in_tree: &[bool],
+             frontier: &mut BinaryHeap<(u64, usize, usize)>| {
+                for neighbour in grid.neighbours(vertex) {
+                    if in_tree[neighbour] {
+                        continue;
+                    }
+
+                    let mut weight = texture.direction_weight(vertex, neighbour);
+                    if texture.is_straight(parent[vertex], vertex, neighbour) {
+                        weight *= straight_weight;
+                    }
+
+                    // the key u^(1/w) of a uniform u is the largest of a set of
+                    // keys with probability proportional to w. The keys are
+                    // non-negative, so their bits are ordered like the keys.
+                    let key = if weight > 0.0 {
+                        rng.gen::<f64>().powf(1.0 / weight)
+                    } else {
+                        0.0
+                    };
+                    frontier.push((key.to_bits(), vertex, neighbour));
+                }
+            };
+
+        in_tree[0] = true;
+        push_frontier(0, &parent, &in_tree, &mut frontier);
+
+        while let Some((_, from, to)) = frontier.pop() {
+            if in_tree[to] {
+                continue;
+            }
+
+            in_tree[to] = true;
+            parent[to] = Some(from);
+            spanning_tree.add_edge(from, to)?;
+            push_frontier(to, &parent, &in_tree, &mut frontier);
+        }
+
+        Ok(spanning_tree)
+    }
+}
+
+impl MazeGenerator for Prim {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        match (&self.texture, &self.weights) {
+            (Some(texture), _) => self.generate_textured(texture, grid, rng),
+            (None, Some(weights)) => {
+                grid.get_minimum_spanning_tree(0, &mut |v1, v2| weights(v1, v2))
+            }
+            (None, None) => grid.get_spanning_tree(0, rng),
+        }
+    }
+}
+
+impl fmt::Debug for Prim {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Prim")
+            .field("texture", &self.texture)
+            .field("weights", &self.weights.as_ref().map(|_| "custom"))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_prim_spanning_tree() {
+        let grid = AdjacencySet::grid(6, 7);
+
+        let tree = Prim::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert_eq!(tree.size(), grid.size());
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_prim_single_cell() {
+        let grid = AdjacencySet::grid(1, 1);
+
+        let tree = Prim::default()
+            .generate(&grid, &mut rand::thread_rng())
+            .unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_prim_weights() {
+        let (h, w) = (6, 8);
+        let grid = AdjacencySet::grid(h, w);
+
+        // a height map rising towards the east makes every vertical passage
+        // free, and leaves a single horizontal passage between each pair of
+        // columns
+        let height = move |v: usize| (v % w) as f64;
+        let prim = Prim::with_weights(move |v1, v2| (height(v1) - height(v2)).abs());
+        let tree = prim.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+        assert!(is_spanning_tree(tree.as_ref()));
+
+        let horizontal = (0..h * w)
+            .filter(|&v| v % w + 1 < w && tree.has_edge(v, v + 1))
+            .count();
+        assert_eq!(horizontal, w - 1);
+    }
+
+    #[test]
+    fn test_prim_textured_spanning_tree() {
+        let grid = AdjacencySet::grid(8, 6);
+
+        for &(bias, straightness) in &[(0.5, 0.0), (1.0, 0.0), (0.0, 1.0), (0.8, 0.6)] {
+            let prim = Prim::with_texture(Texture::new(6, bias, straightness));
+            let tree = prim.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+            assert!(is_spanning_tree(tree.as_ref()));
+        }
+    }
+
+    #[test]
+    fn test_prim_horizontal_bias() {
+        let (h, w) = (10, 10);
+        let grid = AdjacencySet::grid(h, w);
+
+        let prim = Prim::with_texture(Texture::new(w, 0.9, 0.0));
+        let tree = prim
+            .generate(&grid, &mut StdRng::seed_from_u64(17))
+            .unwrap();
+
+        let horizontal = (0..h * w)
+            .filter(|&v| v % w + 1 < w && tree.has_edge(v, v + 1))
+            .count();
+
+        // a horizontal bias should produce far more horizontal passages than
+        // the h * (w - 1) / 2 expected without one
+        assert!(horizontal > h * (w - 1) * 2 / 3);
+    }
+}
@@ -0,0 +1,168 @@
+//! The Recursive Division Algorithm.
+
+use rand::{Rng, RngCore};
+
+use super::MazeGenerator;
+use crate::ds::graphs::Graph;
+use crate::ds::Tiling;
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// Recursive Division is a "wall adder" - unlike the other generators, it
+/// starts from an open field with no walls at all, and divides it in two
+/// with a wall containing a single passage. Each half is then divided in
+/// the same way until the regions are only one cell wide or high. This
+/// produces mazes with long straight walls.
+///
+/// The regions still to be divided are kept on an explicit stack, and the
+/// cells left connected at the end make up the usual spanning tree.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct RecursiveDivision {
+    height: usize,
+    width: usize,
+}
+
+/// a rectangular region of the maze, given by its top-left cell and its
+/// dimensions
+#[derive(Debug, Copy, Clone)]
+struct Region {
+    row: usize,
+    col: usize,
+    height: usize,
+    width: usize,
+}
+
+impl RecursiveDivision {
+    /// create a generator for a maze with the given dimensions
+    pub fn new(height: usize, width: usize) -> Self {
+        RecursiveDivision { height, width }
+    }
+}
+
+impl MazeGenerator for RecursiveDivision {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        // the grid must match the dimensions of the generator
+        if grid.size() != self.height * self.width {
+            return Err(MazeError::of(ErrorKind::InvalidDimensions));
+        }
+
+        let (h, w) = (self.height, self.width);
+
+        // walls between each cell and its eastern and southern neighbours
+        let mut east_walls = vec![false; h * w];
+        let mut south_walls = vec![false; h * w];
+
+        let mut regions = vec![Region {
+            row: 0,
+            col: 0,
+            height: h,
+            width: w,
+        }];
+
+        while let Some(region) = regions.pop() {
+            if region.height < 2 || region.width < 2 {
+                continue;
+            }
+
+            let horizontal = if region.width < region.height {
+                true
+            } else if region.height < region.width {
+                false
+            } else {
+                rng.gen_bool(0.5)
+            };
+
+            if horizontal {
+                // a wall below row `r`, with a passage at column `p`
+                let r = region.row + rng.gen_range(0..region.height - 1);
+                let p = region.col + rng.gen_range(0..region.width);
+                for j in (region.col..region.col + region.width).filter(|&j| j != p) {
+                    south_walls[w * r + j] = true;
+                }
+
+                regions.push(Region {
+                    height: r - region.row + 1,
+                    ..region
+                });
+                regions.push(Region {
+                    row: r + 1,
+                    height: region.row + region.height - r - 1,
+                    ..region
+                });
+            } else {
+                // a wall to the east of column `c`, with a passage at row `p`
+                let c = region.col + rng.gen_range(0..region.width - 1);
+                let p = region.row + rng.gen_range(0..region.height);
+                for i in (region.row..region.row + region.height).filter(|&i| i != p) {
+                    east_walls[w * i + c] = true;
+                }
+
+                regions.push(Region {
+                    width: c - region.col + 1,
+                    ..region
+                });
+                regions.push(Region {
+                    col: c + 1,
+                    width: region.col + region.width - c - 1,
+                    ..region
+                });
+            }
+        }
+
+        let mut spanning_tree = grid.get_empty_copy();
+        for i in 0..h {
+            for j in 0..w {
+                let cell = w * i + j;
+                if j + 1 < w && !east_walls[cell] {
+                    spanning_tree.add_edge(cell, cell + 1)?;
+                }
+                if i + 1 < h && !south_walls[cell] {
+                    spanning_tree.add_edge(cell, cell + w)?;
+                }
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+
+    /// the walls divide the maze along its rows and columns, and a column of
+    /// triangles is not joined from top to bottom
+    fn supports_tiling(&self, tiling: Tiling) -> bool {
+        matches!(tiling, Tiling::Square | Tiling::Hex)
+    }
+
+    /// the walls only divide the rows and columns of a single level
+    fn supports_levels(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::AdjacencySet;
+
+    #[test]
+    fn test_recursive_division_spanning_tree() {
+        for &(h, w) in &[(1, 1), (1, 7), (7, 1), (2, 2), (9, 13), (16, 5)] {
+            let grid = AdjacencySet::grid(h, w);
+
+            let tree = RecursiveDivision::new(h, w)
+                .generate(&grid, &mut rand::thread_rng())
+                .unwrap();
+
+            assert_eq!(tree.size(), grid.size());
+            assert!(is_spanning_tree(tree.as_ref()));
+        }
+    }
+
+    #[test]
+    fn test_recursive_division_wrong_dimensions() {
+        let grid = AdjacencySet::grid(3, 3);
+
+        let tree = RecursiveDivision::new(3, 4).generate(&grid, &mut rand::thread_rng());
+        assert_eq!(tree.err().unwrap().kind(), ErrorKind::InvalidDimensions);
+    }
+}
@@ -0,0 +1,202 @@
+//! Texture controls for the generators which grow passages from cell to cell.
+
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{Rng, RngCore};
+
+///
+/// Parameters which shape the texture of the mazes produced by Prim's
+/// Algorithm and the depth-first style generators (the recursive
+/// backtracker, Hunt-and-Kill and Growing Tree). These apply to the
+/// rectangular grid built by `AdjacencySet::grid`, whose width is needed
+/// to tell horizontal passages from vertical ones - which cannot be told
+/// apart for cells of other shapes, across the edges of a maze which wraps
+/// around, or from the stairs between the levels of a maze.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Texture {
+    width: usize,
+    horizontal_bias: f64,
+    straightness: Option<f64>,
+}
+
+impl Texture {
+    /// create a texture for a grid of the given width. `horizontal_bias` is
+    /// the relative weight of horizontal passages against vertical ones
+    /// (0.5 favours neither), and `straightness` is the probability of a
+    /// passage continuing in the same direction as the one before it, when
+    /// it could turn instead. Both are clamped to the range [0, 1].
+    pub fn new(width: usize, horizontal_bias: f64, straightness: f64) -> Self {
+        Texture {
+            width,
+            horizontal_bias: horizontal_bias.clamp(0.0, 1.0),
+            straightness: Some(straightness.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// create a texture for a grid of the given width with only a horizontal
+    /// bias, which leaves a passage to carry straight on as often as the
+    /// bias would have it
+    pub fn with_bias(width: usize, horizontal_bias: f64) -> Self {
+        Texture {
+            width,
+            horizontal_bias: horizontal_bias.clamp(0.0, 1.0),
+            straightness: None,
+        }
+    }
+
+    // getters
+    pub fn get_horizontal_bias(&self) -> f64 {
+        self.horizontal_bias
+    }
+
+    pub fn get_straightness(&self) -> Option<f64> {
+        self.straightness
+    }
+
+    /// whether the passage from `from` to `to` is horizontal
+    fn is_horizontal(&self, from: usize, to: usize) -> bool {
+        from / self.width == to / self.width
+    }
+
+    /// whether the passage from `current` to `next` continues in the same
+    /// direction as the passage from `previous` to `current`
+    pub(crate) fn is_straight(&self, previous: Option<usize>, current: usize, next: usize) -> bool {
+        previous.map_or(false, |previous| {
+            current as isize - previous as isize == next as isize - current as isize
+        })
+    }
+
+    /// the relative weight of the passage from `from` to `to` according to
+    /// the horizontal bias
+    pub(crate) fn direction_weight(&self, from: usize, to: usize) -> f64 {
+        if self.is_horizontal(from, to) {
+            self.horizontal_bias
+        } else {
+            1.0 - self.horizontal_bias
+        }
+    }
+
+    /// pick the next cell out of `candidates`, for a passage leading out of
+    /// `current` which was itself entered from `previous`
+    pub(crate) fn choose(
+        &self,
+        previous: Option<usize>,
+        current: usize,
+        candidates: &[usize],
+        rng: &mut dyn RngCore,
+    ) -> Option<usize> {
+        let straight = candidates
+            .iter()
+            .copied()
+            .find(|&next| self.is_straight(previous, current, next));
+
+        // once the draw to carry straight on has failed, the passage turns if
+        // there is anywhere else to go
+        let mut excluded = None;
+        if let (Some(next), Some(straightness)) = (straight, self.straightness) {
+            if rng.gen_bool(straightness) {
+                return Some(next);
+            }
+            if candidates.len() > 1 {
+                excluded = Some(next);
+            }
+        }
+
+        // fall back to a uniform choice if the bias rules out every candidate
+        candidates
+            .choose_weighted(rng, |&next| match excluded {
+                Some(excluded) if excluded == next => 0.0,
+                _ => self.direction_weight(current, next),
+            })
+            .ok()
+            .copied()
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|&next| excluded != Some(next))
+                    .choose(rng)
+            })
+    }
+}
+
+/// pick the next cell out of `candidates` according to `texture`, or
+/// uniformly at random if there is none
+pub(crate) fn choose_next(
+    texture: Option<&Texture>,
+    previous: Option<usize>,
+    current: usize,
+    candidates: &[usize],
+    rng: &mut dyn RngCore,
+) -> Option<usize> {
+    match texture {
+        Some(texture) => texture.choose(previous, current, candidates, rng),
+        None => candidates.choose(rng).cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_texture_directions() {
+        let texture = Texture::new(4, 0.75, 0.0);
+
+        assert_eq!(texture.direction_weight(5, 6), 0.75);
+        assert_eq!(texture.direction_weight(5, 9), 0.25);
+        assert!(texture.is_straight(Some(4), 5, 6));
+        assert!(!texture.is_straight(Some(1), 5, 6));
+        assert!(!texture.is_straight(None, 5, 6));
+    }
+
+    #[test]
+    fn test_texture_clamped() {
+        let texture = Texture::new(4, 1.5, -1.0);
+
+        assert_eq!(texture.get_horizontal_bias(), 1.0);
+        assert_eq!(texture.get_straightness(), Some(0.0));
+        assert_eq!(Texture::with_bias(4, 0.5).get_straightness(), None);
+    }
+
+    #[test]
+    fn test_texture_choose() {
+        let mut rng = rand::thread_rng();
+
+        // always continue straight on
+        let straight = Texture::new(4, 0.5, 1.0);
+        assert_eq!(straight.choose(Some(1), 5, &[4, 6, 9], &mut rng), Some(9));
+
+        // only ever go horizontally, unless there is no other choice
+        let horizontal = Texture::new(4, 1.0, 0.0);
+        for _ in 0..20 {
+            let next = horizontal.choose(Some(1), 5, &[1, 6, 9], &mut rng);
+            assert_eq!(next, Some(6));
+        }
+        assert!(horizontal.choose(None, 5, &[1, 9], &mut rng).is_some());
+
+        // never continue straight on while there is another way to go
+        let winding = Texture::new(4, 0.5, 0.0);
+        for _ in 0..20 {
+            assert_ne!(winding.choose(Some(1), 5, &[4, 6, 9], &mut rng), Some(9));
+        }
+        assert_eq!(winding.choose(Some(1), 5, &[9], &mut rng), Some(9));
+    }
+
+    #[test]
+    fn test_texture_straightness_probability() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let texture = Texture::new(4, 0.5, 0.3);
+
+        let trials = 10_000;
+        let straight = (0..trials)
+            .filter(|_| texture.choose(Some(1), 5, &[4, 6, 9], &mut rng) == Some(9))
+            .count();
+
+        // the straight passage is taken only on the draw for it
+        let fraction = straight as f64 / trials as f64;
+        assert!((fraction - 0.3).abs() < 0.03);
+    }
+}
@@ -0,0 +1,142 @@
+//! Wilson's Algorithm (loop-erased random walks).
+
+use rand::{Rng, RngCore};
+
+use super::MazeGenerator;
+use crate::ds::graphs::{algo, Graph};
+use crate::error::{ErrorKind, MazeError, Result};
+
+///
+/// Wilson's Algorithm samples uniformly from all the spanning trees of
+/// the grid. Starting from a single random cell in the tree, a random walk
+/// is performed from each cell not yet in the tree until it hits the tree,
+/// and the walk with its loops erased is then added to the tree. On a
+/// disconnected grid some walks would never end, so it is refused instead.
+///
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Wilson;
+
+impl MazeGenerator for Wilson {
+    fn generate(&self, grid: &dyn Graph, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        let n = grid.size();
+        let mut spanning_tree = grid.get_empty_copy();
+
+        if n == 0 {
+            return Ok(spanning_tree);
+        }
+        if algo::connected_components(grid).0 > 1 {
+            return Err(MazeError::of(ErrorKind::DisconnectedGraph));
+        }
+
+        let mut in_tree = vec![false; n];
+        in_tree[rng.gen_range(0..n)] = true;
+
+        // the exit taken the last time the walk left each cell - overwriting
+        // it on every visit is what erases the loops of the walk
+        let mut next = vec![0; n];
+
+        for start in 0..n {
+            let mut current = start;
+            while !in_tree[current] {
+                // the grid is connected, so every cell has a neighbour
+                let degree = grid.degree(current);
+                debug_assert!(degree > 0);
+                next[current] = grid
+                    .neighbours(current)
+                    .nth(rng.gen_range(0..degree))
+                    .unwrap();
+                current = next[current];
+            }
+
+            current = start;
+            while !in_tree[current] {
+                in_tree[current] = true;
+                spanning_tree.add_edge(current, next[current])?;
+                current = next[current];
+            }
+        }
+
+        Ok(spanning_tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::is_spanning_tree;
+    use super::*;
+    use crate::ds::graphs::{AdjacencySet, GraphType};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_wilson_spanning_tree() {
+        let grid = AdjacencySet::grid(7, 9);
+
+        let tree = Wilson.generate(&grid, &mut rand::thread_rng()).unwrap();
+
+        assert_eq!(tree.size(), grid.size());
+        assert!(is_spanning_tree(tree.as_ref()));
+    }
+
+    /// The 2x3 grid graph has exactly 15 spanning trees, so each of them
+    /// should be sampled with probability 1/15. This runs a chi-squared
+    /// goodness-of-fit test against the uniform distribution.
+    #[test]
+    fn test_wilson_uniform_distribution() {
+        const TREES: usize = 15;
+        const SAMPLES: usize = 15_000;
+        // critical value of the chi-squared distribution with 14 degrees
+        // of freedom at a significance level of 0.001
+        const CRITICAL_VALUE: f64 = 36.12;
+
+        let grid = AdjacencySet::grid(2, 3);
+        let mut rng = StdRng::seed_from_u64(0x6d61_7a65);
+
+        let mut counts: HashMap<Vec<(usize, usize)>, usize> = HashMap::new();
+        for _ in 0..SAMPLES {
+            let tree = Wilson.generate(&grid, &mut rng).unwrap();
+
+            let mut edges = Vec::new();
+            for v in 0..tree.size() {
+                for neighbour in tree.neighbours(v) {
+                    if v < neighbour {
+                        edges.push((v, neighbour));
+                    }
+                }
+            }
+            *counts.entry(edges).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.len(), TREES);
+
+        let expected = (SAMPLES / TREES) as f64;
+        let chi_squared: f64 = counts
+            .values()
+            .map(|&observed| (observed as f64 - expected).powi(2) / expected)
+            .sum();
+
+        assert!(
+            chi_squared < CRITICAL_VALUE,
+            "chi-squared statistic {} exceeds {}",
+            chi_squared,
+            CRITICAL_VALUE
+        );
+    }
+
+    #[test]
+    fn test_wilson_disconnected() {
+        // an isolated cell, and two separate passages
+        let mut split = AdjacencySet::new(4, GraphType::UNDIRECTED);
+        split.add_edge(0, 1).unwrap();
+        split.add_edge(2, 3).unwrap();
+
+        for grid in &[AdjacencySet::new(2, GraphType::UNDIRECTED), split] {
+            let err = Wilson
+                .generate(grid, &mut rand::thread_rng())
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::DisconnectedGraph);
+        }
+    }
+}
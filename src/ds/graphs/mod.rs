@@ -0,0 +1,811 @@
+//! This submodule provides a basic graph framework.
+//! The maze generation algorithms are contained in a submodule, `generators`.
+
+pub mod algo;
+mod bit_grid;
+pub mod generators;
+mod weighted;
+
+pub use self::bit_grid::BitGrid;
+pub use self::weighted::{Weight, WeightedAdjacencySet};
+
+use std::collections::{btree_set, BTreeSet, BinaryHeap};
+
+use rand::{Rng, RngCore};
+
+use crate::ds::polar::PolarGrid;
+use crate::ds::{Tiling, Wrap};
+use crate::error::*;
+use std::cmp;
+
+/// type of graph
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GraphType {
+    DIRECTED,
+    UNDIRECTED,
+}
+
+///
+/// this trait defines the essential behaviour
+/// of a graph
+///
+pub trait Graph {
+    fn add_edge(&mut self, v1: usize, v2: usize) -> Result<()>;
+
+    /// remove the edge between the given vertices, returning whether there
+    /// was one
+    fn remove_edge(&mut self, v1: usize, v2: usize) -> Result<bool>;
+
+    /// iterate over the adjacent vertices of the given vertex, in increasing
+    /// order, without allocating. A vertex outside the graph has none.
+    fn neighbours(&self, v: usize) -> Neighbours<'_>;
+
+    /// retrieve the adjacent vertices of the given vertex, in increasing order
+    fn get_adjacent_vertices(&self, v: usize) -> Result<Vec<usize>> {
+        if v >= self.size() {
+            return Err(MazeError::of(ErrorKind::InvalidVertexOrVertices));
+        }
+
+        Ok(self.neighbours(v).collect())
+    }
+
+    /// the number of vertices adjacent to the given vertex
+    fn degree(&self, v: usize) -> usize {
+        self.neighbours(v).count()
+    }
+
+    /// whether there is an edge from `v1` to `v2`
+    fn has_edge(&self, v1: usize, v2: usize) -> bool {
+        self.neighbours(v1).any(|v| v == v2)
+    }
+
+    /// the number of edges of the graph
+    fn edge_count(&self) -> usize;
+
+    fn get_spanning_tree(&self, v: usize, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>>;
+
+    fn get_minimum_spanning_tree(
+        &self,
+        v: usize,
+        weight: &mut dyn FnMut(usize, usize) -> f64,
+    ) -> Result<Box<dyn Graph>>;
+
+    /// the weight (or cost) of the edge between the given vertices - every
+    /// edge of an unweighted graph weighs 1
+    fn get_weight(&self, _v1: usize, _v2: usize) -> f64 {
+        1.0
+    }
+
+    /// a graph with the same vertices and representation as this one, but
+    /// without any edges
+    fn get_empty_copy(&self) -> Box<dyn Graph>;
+
+    fn size(&self) -> usize;
+
+    /// whether the edges of the graph are directed - graphs are undirected
+    /// unless they say otherwise
+    fn get_kind(&self) -> GraphType {
+        GraphType::UNDIRECTED
+    }
+
+    fn display(&self);
+}
+
+///
+/// graph representation using an adjacency set
+///
+#[derive(Debug, Clone)]
+pub struct AdjacencySet {
+    vertices: Vec<Vertex>,
+    n: usize,
+    kind: GraphType,
+}
+
+impl AdjacencySet {
+    /// create a new adjacency set based graph
+    pub fn new(n: usize, kind: GraphType) -> Self {
+        let mut vs = Vec::new();
+        for _ in 0..n {
+            vs.push(Vertex::new());
+        }
+
+        AdjacencySet {
+            vertices: vs,
+            n,
+            kind,
+        }
+    }
+
+    /// create the undirected grid graph for a maze of the given dimensions -
+    /// every cell is connected to its horizontal and vertical neighbours
+    pub fn grid(height: usize, width: usize) -> Self {
+        AdjacencySet::tiled_grid(height, width, Tiling::Square, GraphType::UNDIRECTED)
+    }
+
+    /// create the directed grid graph for a maze of the given dimensions -
+    /// every cell has an arc to, and an arc from, each of its horizontal and
+    /// vertical neighbours
+    pub fn directed_grid(height: usize, width: usize) -> Self {
+        AdjacencySet::tiled_grid(height, width, Tiling::Square, GraphType::DIRECTED)
+    }
+
+    /// create the grid graph for a maze of the given dimensions whose cells
+    /// have the shape of the given tiling - every cell is connected to each
+    /// of its neighbours, by an arc each way if the graph is directed
+    pub fn tiled_grid(height: usize, width: usize, tiling: Tiling, kind: GraphType) -> Self {
+        if tiling == Tiling::Polar {
+            return AdjacencySet::polar_grid(&PolarGrid::new(height, width), kind);
+        }
+
+        AdjacencySet::from_neighbours(height * width, kind, |v| {
+            tiling.get_neighbours(height, width, v)
+        })
+    }
+
+    /// create the grid graph for the cells of the given polar maze - every
+    /// cell is connected to each of its neighbours, by an arc each way if
+    /// the graph is directed
+    pub fn polar_grid(grid: &PolarGrid, kind: GraphType) -> Self {
+        AdjacencySet::from_neighbours(grid.size(), kind, |v| grid.get_neighbours(v))
+    }
+
+    /// create a graph of `n` vertices, connecting every vertex to each of
+    /// the vertices given by `neighbours`
+    fn from_neighbours<F>(n: usize, kind: GraphType, neighbours: F) -> Self
+    where
+        F: Fn(usize) -> Vec<usize>,
+    {
+        let mut graph = AdjacencySet::new(n, kind);
+
+        for v in 0..n {
+            for u in neighbours(v) {
+                if kind == GraphType::DIRECTED || v < u {
+                    graph.insert_edge(v, u);
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// create the grid graph for a maze of square cells whose edges wrap
+    /// around to the opposite edges - as for `tiled_grid`, but the cells at
+    /// either end of each wrapped row or column are neighbours as well
+    pub fn wrapped_grid(height: usize, width: usize, wrap: Wrap, kind: GraphType) -> Self {
+        let mut graph = AdjacencySet::tiled_grid(height, width, Tiling::Square, kind);
+
+        let mut wrapped = Vec::new();
+        if wrap.wraps_horizontally(width) {
+            wrapped.extend((0..height).map(|i| (width * i + width - 1, width * i)));
+        }
+        if wrap.wraps_vertically(height) {
+            wrapped.extend((0..width).map(|j| (width * (height - 1) + j, j)));
+        }
+
+        for (v1, v2) in wrapped {
+            graph.insert_edge(v1, v2);
+            if kind == GraphType::DIRECTED {
+                graph.insert_edge(v2, v1);
+            }
+        }
+
+        graph
+    }
+
+    /// create the grid graph for a maze of `depth` levels of square cells,
+    /// stacked one above the other - every cell is connected to its
+    /// horizontal and vertical neighbours on its own level, and to the cells
+    /// directly above and below it. Cell `(i, j)` of level `k` is vertex
+    /// `height * width * k + width * i + j`.
+    pub fn levelled_grid(height: usize, width: usize, depth: usize, kind: GraphType) -> Self {
+        let area = height * width;
+        let mut graph = AdjacencySet::new(area * depth, kind);
+
+        for v in 0..area * depth {
+            let (level, cell) = (v / area, v % area);
+            let mut neighbours = Tiling::Square.get_neighbours(height, width, cell);
+            neighbours.iter_mut().for_each(|u| *u += level * area);
+            if level > 0 {
+                neighbours.push(v - area);
+            }
+            if level + 1 < depth {
+                neighbours.push(v + area);
+            }
+
+            for u in neighbours {
+                if kind == GraphType::DIRECTED || v < u {
+                    graph.insert_edge(v, u);
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// add an edge between vertices known to be in the graph
+    fn insert_edge(&mut self, v1: usize, v2: usize) {
+        self.vertices[v1].vs.insert(v2);
+        if self.kind == GraphType::UNDIRECTED {
+            self.vertices[v2].vs.insert(v1);
+        }
+    }
+
+    pub fn dummy() -> Self {
+        AdjacencySet {
+            vertices: Vec::new(),
+            n: 0,
+            kind: GraphType::UNDIRECTED,
+        }
+    }
+}
+
+impl Graph for AdjacencySet {
+    fn add_edge(&mut self, v1: usize, v2: usize) -> Result<()> {
+        if v1 >= self.n || v2 >= self.n {
+            return Err(MazeError::of(ErrorKind::InvalidVertexOrVertices));
+        }
+
+        self.insert_edge(v1, v2);
+        Ok(())
+    }
+
+    fn remove_edge(&mut self, v1: usize, v2: usize) -> Result<bool> {
+        if v1 >= self.n || v2 >= self.n {
+            return Err(MazeError::of(ErrorKind::InvalidVertexOrVertices));
+        }
+
+        let removed = self.vertices[v1].vs.remove(&v2);
+        if self.kind == GraphType::UNDIRECTED {
+            self.vertices[v2].vs.remove(&v1);
+        }
+
+        Ok(removed)
+    }
+
+    fn neighbours(&self, v: usize) -> Neighbours<'_> {
+        match self.vertices.get(v) {
+            Some(vertex) => Neighbours::from_set(&vertex.vs),
+            None => Neighbours::from_cells([None; 4]),
+        }
+    }
+
+    fn degree(&self, v: usize) -> usize {
+        self.vertices.get(v).map_or(0, |vertex| vertex.vs.len())
+    }
+
+    fn has_edge(&self, v1: usize, v2: usize) -> bool {
+        v1 < self.n && self.vertices[v1].vs.contains(&v2)
+    }
+
+    fn edge_count(&self) -> usize {
+        let ends: usize = self.vertices.iter().map(|vertex| vertex.vs.len()).sum();
+
+        // an undirected edge is held at both ends, except for a self-loop
+        // which is held just once
+        match self.kind {
+            GraphType::DIRECTED => ends,
+            GraphType::UNDIRECTED => {
+                let loops = (0..self.n)
+                    .filter(|&v| self.vertices[v].vs.contains(&v))
+                    .count();
+                (ends + loops) / 2
+            }
+        }
+    }
+
+    ///
+    /// Get a spanning tree of the given graph. This is a slight variation of
+    /// Prim's Algorithm and generates a randomised spanning tree.
+    ///
+    /// Every edge is given a random weight the first time it is considered,
+    /// and the minimum spanning tree under those weights is returned. The same
+    /// `rng` state always produces the same spanning tree.
+    ///
+    /// For a directed graph, this is a spanning arborescence rooted at
+    /// `source` instead - every vertex reachable from `source` is reached
+    /// along exactly one path of arcs.
+    ///
+    /// An empty graph has an empty spanning tree. Otherwise `source` must be
+    /// a vertex of the graph.
+    ///
+    fn get_spanning_tree(&self, source: usize, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        random_spanning_tree(self, source, rng)
+    }
+
+    ///
+    /// Get the minimum spanning tree of the component containing `source`
+    /// using Prim's Algorithm, where `weight` gives the weight of the edge
+    /// between two vertices. It is called once for each edge considered, and
+    /// ties are broken by the vertices of the edges.
+    ///
+    fn get_minimum_spanning_tree(
+        &self,
+        source: usize,
+        weight: &mut dyn FnMut(usize, usize) -> f64,
+    ) -> Result<Box<dyn Graph>> {
+        if self.kind == GraphType::DIRECTED {
+            return Err(MazeError::of(ErrorKind::InvalidGraphType));
+        }
+
+        minimum_spanning_tree(self, source, weight)
+    }
+
+    fn get_empty_copy(&self) -> Box<dyn Graph> {
+        Box::new(AdjacencySet::new(self.n, self.kind))
+    }
+
+    /// the number of vertices of the graph
+    fn size(&self) -> usize {
+        self.n
+    }
+
+    fn get_kind(&self) -> GraphType {
+        self.kind
+    }
+
+    fn display(&self) {
+        for vertex in 0..self.n {
+            print!("{} : ", vertex);
+            for neighbour in self.neighbours(vertex) {
+                print!("{} ", neighbour);
+            }
+            println!();
+        }
+    }
+}
+
+/// the spanning tree of `graph` under random edge weights - Prim's Algorithm
+/// weighs every edge exactly once, so each weight is simply drawn from `rng`
+/// when it is asked for
+fn random_spanning_tree(
+    graph: &dyn Graph,
+    source: usize,
+    rng: &mut dyn RngCore,
+) -> Result<Box<dyn Graph>> {
+    minimum_spanning_tree(graph, source, &mut |_, _| rng.gen::<f64>())
+}
+
+/// Prim's Algorithm over `graph`, starting from `source`. On a directed graph
+/// this follows the arcs leading out of the tree, producing an arborescence
+/// (though not necessarily a minimum one).
+fn minimum_spanning_tree(
+    graph: &dyn Graph,
+    source: usize,
+    weight: &mut dyn FnMut(usize, usize) -> f64,
+) -> Result<Box<dyn Graph>> {
+    // an empty graph has an empty spanning tree, whatever the source
+    let mut spanning_tree = graph.get_empty_copy();
+    if graph.size() == 0 {
+        return Ok(spanning_tree);
+    }
+    if source >= graph.size() {
+        return Err(MazeError::of(ErrorKind::InvalidVertexOrVertices));
+    }
+
+    let mut visited = vec![false; graph.size()];
+    visited[source] = true;
+
+    let mut priority_queue = BinaryHeap::new();
+
+    for neighbour in graph.neighbours(source) {
+        if !visited[neighbour] {
+            priority_queue.push(Edge::new(source, neighbour, weight(source, neighbour)));
+        }
+    }
+
+    while let Some(edge) = priority_queue.pop() {
+        if visited[edge.to] {
+            continue;
+        }
+
+        visited[edge.to] = true;
+        for next_neighbour in graph.neighbours(edge.to) {
+            if !visited[next_neighbour] {
+                let w = weight(edge.to, next_neighbour);
+                priority_queue.push(Edge::new(edge.to, next_neighbour, w));
+            }
+        }
+        spanning_tree.add_edge(edge.from, edge.to)?;
+    }
+
+    Ok(spanning_tree)
+}
+
+///
+/// the vertex type for use by AdjacencySet
+///
+#[derive(Debug, Clone)]
+struct Vertex {
+    // the neighbours of this vertex
+    vs: BTreeSet<usize>,
+}
+
+impl Vertex {
+    fn new() -> Self {
+        Vertex {
+            vs: BTreeSet::new(),
+        }
+    }
+}
+
+///
+/// An iterator over the adjacent vertices of a vertex, in increasing order,
+/// as returned by `Graph::neighbours`
+///
+pub struct Neighbours<'a> {
+    inner: NeighboursInner<'a>,
+}
+
+enum NeighboursInner<'a> {
+    // the neighbours held in an adjacency set
+    Set(btree_set::Iter<'a, usize>),
+    // the neighbours of a grid cell which are present
+    Cells(std::array::IntoIter<Option<usize>, 4>),
+}
+
+impl<'a> Neighbours<'a> {
+    fn from_set(vs: &'a BTreeSet<usize>) -> Self {
+        Neighbours {
+            inner: NeighboursInner::Set(vs.iter()),
+        }
+    }
+
+    fn from_cells(cells: [Option<usize>; 4]) -> Self {
+        Neighbours {
+            inner: NeighboursInner::Cells(IntoIterator::into_iter(cells)),
+        }
+    }
+}
+
+impl Iterator for Neighbours<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self.inner {
+            NeighboursInner::Set(ref mut vs) => vs.next().copied(),
+            NeighboursInner::Cells(ref mut cells) => cells.flatten().next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.inner {
+            NeighboursInner::Set(ref vs) => vs.size_hint(),
+            NeighboursInner::Cells(ref cells) => (0, Some(cells.len())),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Neighbours<'_> {
+    fn next_back(&mut self) -> Option<usize> {
+        match self.inner {
+            NeighboursInner::Set(ref mut vs) => vs.next_back().copied(),
+            NeighboursInner::Cells(ref mut cells) => cells.rev().flatten().next(),
+        }
+    }
+}
+
+///
+/// Custom Edge type for Priority Queue used for generaring the spanning tree. The
+/// lightest edge is considered first.
+///
+struct Edge {
+    from: usize,
+    to: usize,
+    weight: f64,
+}
+
+impl Edge {
+    fn new(from: usize, to: usize, weight: f64) -> Self {
+        Edge { from, to, weight }
+    }
+}
+
+///
+/// impl a bunch of traitS so that the `Edge` type can be used in the priority queue used by Prim's
+/// Algorithm to generate the spanning tree. This is because standard Rust does not provide a way
+/// to pass in a custom comparator when creating a `binary_heap` instance (which is the equivalent
+/// of Java's PriorityQueue, albeit a max heap instead of a min heap). The ordering is reversed so
+/// that the heap pops the lightest edge.
+///
+impl cmp::PartialEq for Edge {
+    fn eq(&self, other: &Edge) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl cmp::Eq for Edge {}
+
+impl cmp::PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Edge) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for Edge {
+    fn cmp(&self, other: &Edge) -> cmp::Ordering {
+        other
+            .weight
+            .total_cmp(&self.weight)
+            .then_with(|| (other.from, other.to).cmp(&(self.from, self.to)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// tests that produce output on standard output need to be run with
+    /// the --nocapture argument passed in, as in:
+    ///
+    /// `$cargo test -- --nocapture
+    /// `
+    ///
+    #[test]
+    fn test_generate_spanning_tree() {
+        let mut g = AdjacencySet::new(5, GraphType::UNDIRECTED);
+
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(0, 2).unwrap();
+        g.add_edge(1, 3).unwrap();
+        g.add_edge(2, 4).unwrap();
+        g.add_edge(4, 1).unwrap();
+        g.add_edge(4, 3).unwrap();
+
+        let spanning_tree = g.get_spanning_tree(0, &mut rand::thread_rng()).unwrap();
+
+        spanning_tree.display();
+    }
+
+    #[test]
+    fn test_spanning_tree_params() {
+        let mut g = AdjacencySet::new(5, GraphType::UNDIRECTED);
+
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(0, 2).unwrap();
+        g.add_edge(0, 3).unwrap();
+        g.add_edge(2, 4).unwrap();
+
+        let spanning_tree = g.get_spanning_tree(0, &mut rand::thread_rng()).unwrap();
+        assert_eq!(spanning_tree.size(), g.size());
+    }
+
+    #[test]
+    fn test_spanning_tree_reproducible() {
+        let g = AdjacencySet::grid(6, 6);
+
+        let first = g
+            .get_spanning_tree(0, &mut StdRng::seed_from_u64(7))
+            .unwrap();
+        let second = g
+            .get_spanning_tree(0, &mut StdRng::seed_from_u64(7))
+            .unwrap();
+
+        for v in 0..g.size() {
+            assert_eq!(
+                first.get_adjacent_vertices(v).unwrap(),
+                second.get_adjacent_vertices(v).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let mut g = AdjacencySet::new(4, GraphType::UNDIRECTED);
+
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(1, 2).unwrap();
+        g.add_edge(2, 3).unwrap();
+        g.add_edge(3, 0).unwrap();
+        g.add_edge(0, 2).unwrap();
+
+        // the heaviest edges, 0 - 3 and 1 - 2, are left out
+        let weights = [
+            [0.0, 1.0, 2.0, 5.0],
+            [1.0, 0.0, 4.0, 0.0],
+            [2.0, 4.0, 0.0, 3.0],
+        ];
+        let mst = g
+            .get_minimum_spanning_tree(0, &mut |v1, v2| weights[cmp::min(v1, v2)][cmp::max(v1, v2)])
+            .unwrap();
+
+        assert_eq!(mst.get_adjacent_vertices(0).unwrap(), vec![1, 2]);
+        assert_eq!(mst.get_adjacent_vertices(2).unwrap(), vec![0, 3]);
+        assert_eq!(mst.get_adjacent_vertices(1).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_edge_ordering() {
+        let mut priority_queue = BinaryHeap::new();
+
+        priority_queue.push(Edge::new(0, 1, 0.5));
+        priority_queue.push(Edge::new(0, 2, 0.25));
+        priority_queue.push(Edge::new(1, 2, 0.5));
+
+        let order: Vec<_> = std::iter::from_fn(|| priority_queue.pop())
+            .map(|edge| (edge.from, edge.to))
+            .collect();
+        assert_eq!(order, vec![(0, 2), (0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut g = AdjacencySet::new(4, GraphType::UNDIRECTED);
+
+        g.add_edge(2, 3).unwrap();
+        g.add_edge(2, 0).unwrap();
+        g.add_edge(2, 1).unwrap();
+
+        assert_eq!(g.neighbours(2).collect::<Vec<_>>(), vec![0, 1, 3]);
+        assert_eq!(g.neighbours(2).rev().collect::<Vec<_>>(), vec![3, 1, 0]);
+        assert_eq!(g.degree(2), 3);
+        assert!(g.has_edge(3, 2));
+        assert!(!g.has_edge(0, 1));
+        assert_eq!(g.edge_count(), 3);
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut g = AdjacencySet::new(3, GraphType::DIRECTED);
+
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(1, 0).unwrap();
+
+        assert!(g.remove_edge(0, 1).unwrap());
+        assert!(!g.remove_edge(0, 1).unwrap());
+        assert!(!g.has_edge(0, 1));
+        assert!(g.has_edge(1, 0));
+        assert_eq!(g.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_add_edge_invalid() {
+        let mut g = AdjacencySet::new(1, GraphType::DIRECTED);
+
+        let err = g.add_edge(0, 1).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidVertexOrVertices);
+        assert!(g.remove_edge(1, 0).is_err());
+    }
+
+    #[test]
+    fn test_get_adjancent_vertices_invalid() {
+        let mut g = AdjacencySet::new(2, GraphType::UNDIRECTED);
+
+        g.add_edge(0, 1).unwrap();
+
+        assert!(g.get_adjacent_vertices(2).is_err());
+        assert_eq!(g.neighbours(2).count(), 0);
+        assert_eq!(g.degree(2), 0);
+    }
+
+    #[test]
+    fn test_get_spanning_tree_directed() {
+        let g = AdjacencySet::directed_grid(4, 5);
+        let root = 7;
+
+        let tree = g.get_spanning_tree(root, &mut rand::thread_rng()).unwrap();
+
+        // every vertex but the root is entered along exactly one arc, and
+        // no arc runs both ways
+        let mut in_degree = vec![0; g.size()];
+        for v in 0..g.size() {
+            for u in tree.neighbours(v) {
+                in_degree[u] += 1;
+                assert!(!tree.has_edge(u, v));
+            }
+        }
+        assert_eq!(in_degree[root], 0);
+        assert!((0..g.size()).all(|v| v == root || in_degree[v] == 1));
+    }
+
+    #[test]
+    fn test_get_minimum_spanning_tree_directed() {
+        let g = AdjacencySet::directed_grid(2, 2);
+
+        let err = g
+            .get_minimum_spanning_tree(0, &mut |_, _| 1.0)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidGraphType);
+    }
+
+    #[test]
+    fn test_directed_grid() {
+        let g = AdjacencySet::directed_grid(2, 3);
+
+        assert_eq!(g.get_kind(), GraphType::DIRECTED);
+        assert_eq!(g.edge_count(), 2 * AdjacencySet::grid(2, 3).edge_count());
+        assert!(g.has_edge(1, 4) && g.has_edge(4, 1));
+    }
+
+    #[test]
+    fn test_wrapped_grid() {
+        let (h, w) = (3, 4);
+        let grid = AdjacencySet::grid(h, w).edge_count();
+
+        let cylinder = AdjacencySet::wrapped_grid(h, w, Wrap::Horizontal, GraphType::UNDIRECTED);
+        assert_eq!(cylinder.edge_count(), grid + h);
+        assert!(cylinder.has_edge(3, 0) && cylinder.has_edge(11, 8));
+        assert!(!cylinder.has_edge(8, 0));
+
+        let torus = AdjacencySet::wrapped_grid(h, w, Wrap::Both, GraphType::UNDIRECTED);
+        assert_eq!(torus.edge_count(), grid + h + w);
+        assert!(torus.has_edge(8, 0) && torus.has_edge(11, 3));
+        assert!(torus.neighbours(0).eq(vec![1, 3, 4, 8]));
+
+        let directed = AdjacencySet::wrapped_grid(h, w, Wrap::Both, GraphType::DIRECTED);
+        assert_eq!(directed.edge_count(), 2 * torus.edge_count());
+
+        // a maze two cells high has no room to wrap around
+        let flat = AdjacencySet::wrapped_grid(2, w, Wrap::Vertical, GraphType::UNDIRECTED);
+        assert_eq!(flat.edge_count(), AdjacencySet::grid(2, w).edge_count());
+    }
+
+    #[test]
+    fn test_levelled_grid() {
+        let (h, w, depth) = (2, 3, 3);
+        let g = AdjacencySet::levelled_grid(h, w, depth, GraphType::UNDIRECTED);
+
+        // each level is a grid, and each pair of levels is joined cell by cell
+        let level = AdjacencySet::grid(h, w).edge_count();
+        assert_eq!(g.edge_count(), depth * level + (depth - 1) * h * w);
+        assert!(g.has_edge(1, 7) && g.has_edge(7, 13) && !g.has_edge(1, 13));
+        assert!(g.has_edge(12, 13) && !g.has_edge(5, 6));
+
+        let directed = AdjacencySet::levelled_grid(h, w, depth, GraphType::DIRECTED);
+        assert_eq!(directed.edge_count(), 2 * g.edge_count());
+
+        let tree = g
+            .get_spanning_tree(0, &mut StdRng::seed_from_u64(8))
+            .unwrap();
+        assert!(generators::is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_hex_grid() {
+        let (h, w) = (4, 5);
+        let g = AdjacencySet::tiled_grid(h, w, Tiling::Hex, GraphType::UNDIRECTED);
+
+        // every pair of neighbouring rows is joined by 2w - 1 edges
+        assert_eq!(g.edge_count(), h * (w - 1) + (h - 1) * (2 * w - 1));
+        assert!(g.has_edge(0, 5) && !g.has_edge(0, 6));
+        assert!(g.has_edge(5, 11) && !g.has_edge(5, 9));
+
+        let tree = g
+            .get_spanning_tree(0, &mut StdRng::seed_from_u64(8))
+            .unwrap();
+        assert!(generators::is_spanning_tree(tree.as_ref()));
+    }
+
+    #[test]
+    fn test_polar_grid() {
+        let polar = PolarGrid::new(4, 6);
+        let g = AdjacencySet::tiled_grid(4, 6, Tiling::Polar, GraphType::UNDIRECTED);
+
+        assert_eq!(g.size(), polar.size());
+        for v in 0..g.size() {
+            assert_eq!(g.get_adjacent_vertices(v).unwrap(), polar.get_neighbours(v));
+        }
+    }
+
+    #[test]
+    fn test_get_spanning_tree_invalid() {
+        let mut g = AdjacencySet::new(5, GraphType::UNDIRECTED);
+
+        g.add_edge(0, 1).unwrap();
+        g.add_edge(0, 2).unwrap();
+        g.add_edge(2, 4).unwrap();
+        g.add_edge(4, 1).unwrap();
+
+        assert!(g.get_spanning_tree(10, &mut rand::thread_rng()).is_err());
+
+        // the vertices run from 0 to n - 1
+        let err = g
+            .get_spanning_tree(g.size(), &mut rand::thread_rng())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidVertexOrVertices);
+        assert!(g.get_minimum_spanning_tree(5, &mut |_, _| 1.0).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+//! A graph whose edges carry a weight, such as the cost of crossing the
+//! terrain (mud, water) of a passage.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use rand::RngCore;
+
+use super::{AdjacencySet, Graph, GraphType, Neighbours};
+use crate::error::*;
+
+///
+/// the types that may be used as edge weights
+///
+pub trait Weight: Copy + Display + Into<f64> + 'static {
+    /// the weight given to an edge added without one
+    fn unit() -> Self;
+}
+
+impl Weight for u32 {
+    fn unit() -> Self {
+        1
+    }
+}
+
+impl Weight for f64 {
+    fn unit() -> Self {
+        1.0
+    }
+}
+
+///
+/// graph representation using an adjacency set, along with the weight of
+/// every edge
+///
+#[derive(Debug, Clone)]
+pub struct WeightedAdjacencySet<W: Weight = f64> {
+    edges: AdjacencySet,
+    weights: HashMap<(usize, usize), W>,
+}
+
+impl<W: Weight> WeightedAdjacencySet<W> {
+    /// create a new weighted graph with no edges
+    pub fn new(n: usize, kind: GraphType) -> Self {
+        WeightedAdjacencySet {
+            edges: AdjacencySet::new(n, kind),
+            weights: HashMap::new(),
+        }
+    }
+
+    /// create the undirected grid graph for a maze of the given dimensions,
+    /// where `weight` gives the weight of the edge between neighbouring cells
+    pub fn grid<F>(height: usize, width: usize, mut weight: F) -> Self
+    where
+        F: FnMut(usize, usize) -> W,
+    {
+        let mut graph = WeightedAdjacencySet::new(height * width, GraphType::UNDIRECTED);
+
+        for i in 0..height {
+            for j in 0..width {
+                let v = width * i + j;
+                if j + 1 < width {
+                    graph.insert_weighted_edge(v, v + 1, weight(v, v + 1));
+                }
+                if i + 1 < height {
+                    graph.insert_weighted_edge(v, v + width, weight(v, v + width));
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// the key of the weight of the edge between the given vertices - both
+    /// directions of an undirected edge share a weight
+    fn key(&self, v1: usize, v2: usize) -> (usize, usize) {
+        match self.edges.kind {
+            GraphType::DIRECTED => (v1, v2),
+            GraphType::UNDIRECTED => (v1.min(v2), v1.max(v2)),
+        }
+    }
+
+    /// add a weighted edge between vertices known to be in the graph
+    fn insert_weighted_edge(&mut self, v1: usize, v2: usize, weight: W) {
+        self.edges.insert_edge(v1, v2);
+        self.weights.insert(self.key(v1, v2), weight);
+    }
+
+    /// add an edge with the given weight, replacing the weight of the edge
+    /// if it is already present
+    pub fn add_weighted_edge(&mut self, v1: usize, v2: usize, weight: W) -> Result<()> {
+        if v1 >= self.size() || v2 >= self.size() {
+            return Err(MazeError::of(ErrorKind::InvalidVertexOrVertices));
+        }
+
+        self.insert_weighted_edge(v1, v2, weight);
+        Ok(())
+    }
+
+    /// the weight of the edge between the given vertices, if there is one
+    pub fn get_edge_weight(&self, v1: usize, v2: usize) -> Option<W> {
+        self.weights.get(&self.key(v1, v2)).copied()
+    }
+
+    /// change the weight of an existing edge, returning false if there is
+    /// no such edge
+    pub fn set_edge_weight(&mut self, v1: usize, v2: usize, weight: W) -> bool {
+        match self.weights.get_mut(&self.key(v1, v2)) {
+            Some(w) => {
+                *w = weight;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// copy the edges of `subgraph`, which must all be edges of this graph,
+    /// into a weighted graph carrying their weights from this one
+    fn weighted_subgraph(&self, subgraph: &dyn Graph) -> Box<dyn Graph> {
+        let mut graph = WeightedAdjacencySet::new(self.size(), self.edges.kind);
+
+        for v in 0..subgraph.size() {
+            for u in subgraph.neighbours(v) {
+                graph.insert_weighted_edge(v, u, self.weights[&self.key(v, u)]);
+            }
+        }
+
+        Box::new(graph)
+    }
+
+    /// retrieve the adjacent vertices of the given vertex along with the
+    /// weights of the edges leading to them
+    pub fn get_weighted_adjacent_vertices(&self, v: usize) -> Result<Vec<(usize, W)>> {
+        let vs = self.get_adjacent_vertices(v)?;
+
+        Ok(vs
+            .into_iter()
+            .map(|u| (u, self.weights[&self.key(v, u)]))
+            .collect())
+    }
+}
+
+impl<W: Weight> Graph for WeightedAdjacencySet<W> {
+    /// add an edge of unit weight, keeping the weight of an existing edge
+    fn add_edge(&mut self, v1: usize, v2: usize) -> Result<()> {
+        self.edges.add_edge(v1, v2)?;
+        self.weights.entry(self.key(v1, v2)).or_insert_with(W::unit);
+        Ok(())
+    }
+
+    fn remove_edge(&mut self, v1: usize, v2: usize) -> Result<bool> {
+        let removed = self.edges.remove_edge(v1, v2)?;
+        self.weights.remove(&self.key(v1, v2));
+        Ok(removed)
+    }
+
+    fn neighbours(&self, v: usize) -> Neighbours<'_> {
+        self.edges.neighbours(v)
+    }
+
+    fn degree(&self, v: usize) -> usize {
+        self.edges.degree(v)
+    }
+
+    fn has_edge(&self, v1: usize, v2: usize) -> bool {
+        self.edges.has_edge(v1, v2)
+    }
+
+    fn edge_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// a randomised spanning tree which, like that of `AdjacencySet`, ignores
+    /// the weights of the edges while choosing them, but keeps them in the tree
+    fn get_spanning_tree(&self, source: usize, rng: &mut dyn RngCore) -> Result<Box<dyn Graph>> {
+        let tree = self.edges.get_spanning_tree(source, rng)?;
+        Ok(self.weighted_subgraph(tree.as_ref()))
+    }
+
+    /// a minimum spanning tree under `weight`, keeping the weights of this
+    /// graph in the tree
+    fn get_minimum_spanning_tree(
+        &self,
+        source: usize,
+        weight: &mut dyn FnMut(usize, usize) -> f64,
+    ) -> Result<Box<dyn Graph>> {
+        let tree = self.edges.get_minimum_spanning_tree(source, weight)?;
+        Ok(self.weighted_subgraph(tree.as_ref()))
+    }
+
+    fn get_empty_copy(&self) -> Box<dyn Graph> {
+        Box::new(WeightedAdjacencySet::<W>::new(self.size(), self.edges.kind))
+    }
+
+    /// the weight of the edge between the given vertices, or infinity if
+    /// they are not adjacent
+    fn get_weight(&self, v1: usize, v2: usize) -> f64 {
+        self.get_edge_weight(v1, v2)
+            .map_or(f64::INFINITY, |weight| weight.into())
+    }
+
+    fn size(&self) -> usize {
+        self.edges.size()
+    }
+
+    fn get_kind(&self) -> GraphType {
+        self.edges.kind
+    }
+
+    fn display(&self) {
+        for vertex in 0..self.size() {
+            print!("{} : ", vertex);
+            for neighbour in self.neighbours(vertex) {
+                let weight = self.weights[&self.key(vertex, neighbour)];
+                print!("{} ({}) ", neighbour, weight);
+            }
+            println!();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_edges() {
+        let mut g = WeightedAdjacencySet::<u32>::new(3, GraphType::UNDIRECTED);
+
+        g.add_weighted_edge(0, 1, 5).unwrap();
+        g.add_edge(1, 2).unwrap();
+
+        assert_eq!(g.get_edge_weight(1, 0), Some(5));
+        assert_eq!(g.get_edge_weight(2, 1), Some(1));
+        assert_eq!(g.get_edge_weight(0, 2), None);
+        assert_eq!(
+            g.get_weighted_adjacent_vertices(1).unwrap(),
+            vec![(0, 5), (2, 1)]
+        );
+        assert_eq!(g.get_adjacent_vertices(1).unwrap(), vec![0, 2]);
+
+        assert_eq!(g.edge_count(), 2);
+        assert!(g.remove_edge(2, 1).unwrap());
+        assert_eq!(g.get_edge_weight(1, 2), None);
+        assert_eq!(g.edge_count(), 1);
+
+        assert!(g.set_edge_weight(0, 1, 7));
+        assert!(!g.set_edge_weight(0, 2, 7));
+        assert_eq!(g.get_weight(1, 0), 7.0);
+        assert_eq!(g.get_weight(0, 2), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_directed_weights() {
+        let mut g = WeightedAdjacencySet::new(2, GraphType::DIRECTED);
+
+        g.add_weighted_edge(0, 1, 0.5).unwrap();
+
+        assert_eq!(g.get_edge_weight(0, 1), Some(0.5));
+        assert_eq!(g.get_edge_weight(1, 0), None);
+    }
+
+    #[test]
+    fn test_weighted_grid_spanning_tree() {
+        let (h, w) = (4, 5);
+
+        // water along the middle row makes its horizontal passages costly
+        let g = WeightedAdjacencySet::grid(h, w, |v1, v2| {
+            if v1 / w == 2 && v2 / w == 2 {
+                10.0
+            } else {
+                1.0
+            }
+        });
+
+        let tree = g
+            .get_minimum_spanning_tree(0, &mut |v1, v2| g.get_weight(v1, v2))
+            .unwrap();
+        let edges: usize = (0..h * w)
+            .map(|v| tree.get_adjacent_vertices(v).unwrap().len())
+            .sum();
+
+        assert_eq!(edges / 2, h * w - 1);
+        for v in 2 * w..3 * w - 1 {
+            assert!(!tree.get_adjacent_vertices(v).unwrap().contains(&(v + 1)));
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree_keeps_weights() {
+        let g = WeightedAdjacencySet::<u32>::grid(3, 4, |v1, v2| (v1 + v2) as u32);
+
+        let trees = vec![
+            g.get_spanning_tree(0, &mut rand::thread_rng()).unwrap(),
+            g.get_minimum_spanning_tree(0, &mut |_, _| 1.0).unwrap(),
+        ];
+
+        for tree in trees {
+            assert_eq!(tree.edge_count(), g.size() - 1);
+            for v in 0..tree.size() {
+                for u in tree.neighbours(v) {
+                    assert_eq!(tree.get_weight(v, u), (v + u) as f64);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_weighted_edge_invalid() {
+        let mut g = WeightedAdjacencySet::new(1, GraphType::UNDIRECTED);
+
+        assert!(g.add_weighted_edge(0, 1, 2.0).is_err());
+        assert!(g.get_weighted_adjacent_vertices(1).is_err());
+    }
+}
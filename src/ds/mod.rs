@@ -1,7 +1,14 @@
 //! This module contains the essential data structures used by the Maze project
 //! The basic graph types are contained in a submodule, `graphs`.
 
+pub mod disjoint_set;
 pub mod graphs;
+pub mod polar;
+
+use std::str::FromStr;
+
+use self::polar::PolarGrid;
+use crate::error::*;
 
 ///
 /// A two-dimensional point representing the location of a cell of the maze
@@ -38,29 +45,249 @@ pub enum Direction {
     South,
     East,
     West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+    Up,
+    Down,
+}
+
+impl Direction {
+    /// the direction leading back the way this one came
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+            Direction::NorthEast => Direction::SouthWest,
+            Direction::NorthWest => Direction::SouthEast,
+            Direction::SouthEast => Direction::NorthWest,
+            Direction::SouthWest => Direction::NorthEast,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+}
+
+///
+/// The shape of the cells of the maze, and so the way in which they are
+/// laid out and which cells neighbour each other. The cells are laid out in
+/// rows, and cell `(i, j)` is vertex `width * i + j` - except in a polar
+/// maze, whose rows are rings of different widths (see `polar::PolarGrid`).
+///
+/// Square cells have four neighbours - north, south, east and west.
+/// Hexagonal cells have six - east, west, and two in each of the rows above
+/// and below. Every odd row of hexagons is shifted half a cell to the east,
+/// so that the cells diagonally below cell `(i, j)` are `(i + 1, j - 1)` and
+/// `(i + 1, j)` in an even row, and `(i + 1, j)` and `(i + 1, j + 1)` in an
+/// odd row.
+///
+/// Triangular (delta) cells have three - east, west, and the cell directly
+/// below a triangle pointing up, or directly above one pointing down. Cell
+/// `(i, j)` points up when `i + j` is even.
+///
+/// Polar (theta) cells lie in rings around a central cell, the height being
+/// the number of rings and the width the number of cells in the first ring.
+/// Each cell has a neighbour clockwise and counter-clockwise around its
+/// ring, one inward, and one or more outward.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Tiling {
+    #[default]
+    Square,
+    Hex,
+    Delta,
+    Polar,
+}
+
+///
+/// The shape of a single cell, which decides how its walls are drawn
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum CellShape {
+    #[default]
+    Square,
+    Hexagon,
+    UpTriangle,
+    DownTriangle,
+    Sector,
+}
+
+impl Tiling {
+    /// the cells neighbouring cell `v` of a maze with the given dimensions,
+    /// in increasing order. A polar maze is laid out afresh on every call,
+    /// so `PolarGrid` should be used for more than a single cell.
+    pub fn get_neighbours(&self, height: usize, width: usize, v: usize) -> Vec<usize> {
+        if *self == Tiling::Polar {
+            return PolarGrid::new(height, width).get_neighbours(v);
+        }
+
+        let (i, j) = (v / width, v % width);
+        let mut neighbours = Vec::new();
+
+        // the columns of the neighbours in the rows above and below
+        let (l, r) = match self {
+            Tiling::Square | Tiling::Delta | Tiling::Polar => (j, j),
+            Tiling::Hex if i % 2 == 0 => (j.saturating_sub(1), j),
+            Tiling::Hex => (j, (j + 1).min(width - 1)),
+        };
+        let (above, below) = match self.get_cell_shape(i, j) {
+            CellShape::UpTriangle => (false, true),
+            CellShape::DownTriangle => (true, false),
+            _ => (true, true),
+        };
+
+        if i > 0 && above {
+            neighbours.extend((l..=r).map(|c| width * (i - 1) + c));
+        }
+        if j > 0 {
+            neighbours.push(v - 1);
+        }
+        if j + 1 < width {
+            neighbours.push(v + 1);
+        }
+        if i + 1 < height && below {
+            neighbours.extend((l..=r).map(|c| width * (i + 1) + c));
+        }
+
+        neighbours
+    }
+
+    /// the number of cells in each row of a maze with the given dimensions
+    pub fn get_row_widths(&self, height: usize, width: usize) -> Vec<usize> {
+        match self {
+            Tiling::Polar => {
+                let grid = PolarGrid::new(height, width);
+                (0..height).map(|r| grid.get_ring_width(r)).collect()
+            }
+            _ => vec![width; height],
+        }
+    }
+
+    /// the number of cells of a maze with the given dimensions
+    pub fn get_cell_count(&self, height: usize, width: usize) -> usize {
+        self.get_row_widths(height, width).iter().sum()
+    }
+
+    /// the shape of cell `(i, j)`
+    pub fn get_cell_shape(&self, i: usize, j: usize) -> CellShape {
+        match self {
+            Tiling::Square => CellShape::Square,
+            Tiling::Hex => CellShape::Hexagon,
+            Tiling::Delta if (i + j) % 2 == 0 => CellShape::UpTriangle,
+            Tiling::Delta => CellShape::DownTriangle,
+            Tiling::Polar => CellShape::Sector,
+        }
+    }
+
+    /// whether the maze can be drawn on the terminal - the curved walls of
+    /// a polar maze can only be drawn as an image
+    pub fn is_drawable_on_terminal(&self) -> bool {
+        *self != Tiling::Polar
+    }
+}
+
+impl FromStr for Tiling {
+    type Err = MazeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "square" => Ok(Tiling::Square),
+            "hex" => Ok(Tiling::Hex),
+            "delta" => Ok(Tiling::Delta),
+            "polar" => Ok(Tiling::Polar),
+            _ => Err(MazeError::of(ErrorKind::InvalidTiling)),
+        }
+    }
+}
+
+///
+/// The edges of a maze of square cells which lead around to the opposite
+/// edge - the east and west edges of a maze wrapped horizontally into a
+/// cylinder, the north and south edges of one wrapped vertically, or both
+/// for a torus. An edge only wraps around if the maze is more than two cells
+/// across, since the cells on either side would otherwise already be
+/// neighbours.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Wrap {
+    #[default]
+    None,
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+impl Wrap {
+    /// whether the east and west edges of a maze of the given width wrap
+    /// around to each other
+    pub fn wraps_horizontally(&self, width: usize) -> bool {
+        matches!(self, Wrap::Horizontal | Wrap::Both) && width > 2
+    }
+
+    /// whether the north and south edges of a maze of the given height wrap
+    /// around to each other
+    pub fn wraps_vertically(&self, height: usize) -> bool {
+        matches!(self, Wrap::Vertical | Wrap::Both) && height > 2
+    }
+}
+
+impl FromStr for Wrap {
+    type Err = MazeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "none" => Ok(Wrap::None),
+            "horizontal" => Ok(Wrap::Horizontal),
+            "vertical" => Ok(Wrap::Vertical),
+            "both" => Ok(Wrap::Both),
+            _ => Err(MazeError::of(ErrorKind::InvalidWrap)),
+        }
+    }
 }
 
 ///
 /// Represents a cell of the maze. `id` maps to the vertex id of the
 /// equivalent undirected graph, and `location` simply refers to the
 /// coordinates of this cell on the screen, for the purposes of
-/// rendering the cell.
+/// rendering the cell. `shape` decides which walls the cell has, and
+/// how they are drawn around its location.
 ///
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct CellData {
     id: usize,
     location: Point,
+    shape: CellShape,
 }
 
 impl CellData {
     pub fn new(id: usize, location: Point) -> Self {
-        CellData { id, location }
+        CellData::with_shape(id, location, CellShape::Square)
+    }
+
+    /// a cell of the given shape
+    pub fn with_shape(id: usize, location: Point, shape: CellShape) -> Self {
+        CellData {
+            id,
+            location,
+            shape,
+        }
     }
 
     // getters
+    pub fn get_id(&self) -> usize {
+        self.id
+    }
+
     pub fn get_location(&self) -> &Point {
         &self.location
     }
+
+    pub fn get_shape(&self) -> CellShape {
+        self.shape
+    }
 }
 
 ///
@@ -72,14 +299,28 @@ impl CellData {
 pub struct MazeData {
     height: usize,
     width: usize,
+    tiling: Tiling,
+    wrap: Wrap,
     cells: Vec<Vec<CellData>>,
 }
 
 impl MazeData {
     pub fn new(height: usize, width: usize, cells: Vec<Vec<CellData>>) -> Self {
+        MazeData::with_tiling(height, width, Tiling::Square, cells)
+    }
+
+    /// a maze whose cells have the shape of the given tiling
+    pub fn with_tiling(
+        height: usize,
+        width: usize,
+        tiling: Tiling,
+        cells: Vec<Vec<CellData>>,
+    ) -> Self {
         MazeData {
             height,
             width,
+            tiling,
+            wrap: Wrap::None,
             cells,
         }
     }
@@ -93,6 +334,25 @@ impl MazeData {
         self.width
     }
 
+    pub fn get_tiling(&self) -> Tiling {
+        self.tiling
+    }
+
+    pub fn get_wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    /// wrap the edges of the maze around to the opposite edges
+    pub fn set_wrap(&mut self, wrap: Wrap) {
+        self.wrap = wrap;
+    }
+
+    /// the number of cells in row `i` - the same for every row except in a
+    /// polar maze
+    pub fn get_row_width(&self, i: usize) -> usize {
+        self.cells[i].len()
+    }
+
     // for the given coordinates, return the actual cell metadata
     // that this MazeData instance holds
     pub fn get_cell(&self, x: usize, y: usize) -> &CellData {
@@ -111,12 +371,62 @@ mod tests {
         assert_eq!(p, Point { x: 1, y: 2 });
     }
 
+    #[test]
+    fn test_tiling_neighbours() {
+        assert_eq!(Tiling::Square.get_neighbours(3, 4, 5), vec![1, 4, 6, 9]);
+        assert_eq!(Tiling::Square.get_neighbours(3, 4, 0), vec![1, 4]);
+
+        // even rows lean west, odd rows lean east
+        assert_eq!(Tiling::Hex.get_neighbours(3, 4, 5), vec![1, 2, 4, 6, 9, 10]);
+        assert_eq!(Tiling::Hex.get_neighbours(3, 4, 9), vec![4, 5, 8, 10]);
+        assert_eq!(Tiling::Hex.get_neighbours(3, 4, 7), vec![3, 6, 11]);
+        assert_eq!(Tiling::Hex.get_neighbours(3, 4, 8), vec![4, 9]);
+
+        // up triangles lead down, and down triangles lead up
+        assert_eq!(Tiling::Delta.get_neighbours(3, 4, 5), vec![4, 6, 9]);
+        assert_eq!(Tiling::Delta.get_neighbours(3, 4, 6), vec![2, 5, 7]);
+        assert_eq!(Tiling::Delta.get_neighbours(3, 4, 0), vec![1, 4]);
+        assert_eq!(Tiling::Delta.get_cell_shape(1, 2), CellShape::DownTriangle);
+
+        assert_eq!(
+            Tiling::Polar.get_neighbours(3, 6, 0),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(Tiling::Polar.get_row_widths(3, 6), vec![1, 6, 12]);
+        assert_eq!(Tiling::Polar.get_cell_count(3, 6), 19);
+        assert_eq!(Tiling::Hex.get_cell_count(3, 6), 18);
+    }
+
+    #[test]
+    fn test_tiling_from_str() {
+        assert_eq!(Tiling::from_str("hex").unwrap(), Tiling::Hex);
+        assert_eq!(Tiling::default(), Tiling::Square);
+
+        let err = Tiling::from_str("octagon").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidTiling);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(Wrap::from_str("both").unwrap(), Wrap::Both);
+        assert_eq!(Wrap::default(), Wrap::None);
+
+        assert!(Wrap::Horizontal.wraps_horizontally(3));
+        assert!(!Wrap::Horizontal.wraps_vertically(3));
+        assert!(Wrap::Both.wraps_vertically(3));
+        assert!(!Wrap::Both.wraps_horizontally(2));
+
+        let err = Wrap::from_str("klein").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidWrap);
+    }
+
     #[test]
     fn teste_maze_data_sanity() {
         let maze_data = MazeData::new(10, 20, Vec::new());
 
         assert_eq!(maze_data.get_height(), 10);
         assert_eq!(maze_data.get_width(), 20);
+        assert_eq!(maze_data.get_tiling(), Tiling::Square);
     }
 
     #[test]
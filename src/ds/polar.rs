@@ -0,0 +1,202 @@
+//! This submodule lays out the cells of a polar (circular) maze.
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+///
+/// The cells of a polar maze - a single cell at the centre, surrounded by
+/// rings of cells. Each ring is as tall as a cell of the innermost ring is
+/// wide, and the cells of a ring are split in two (or more) whenever they
+/// would otherwise be much wider than they are tall, so that the cells stay
+/// roughly the same size all the way out.
+///
+/// Cell `c` of ring `r` is numbered ring by ring from the centre, which is
+/// vertex 0, and its cells run clockwise from the east.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolarGrid {
+    rings: Vec<usize>,
+    offsets: Vec<usize>,
+}
+
+impl PolarGrid {
+    /// lay out a maze of `rings` rings, counting the centre as the first,
+    /// with `width` cells in the ring around the centre
+    pub fn new(rings: usize, width: usize) -> Self {
+        let mut widths = Vec::with_capacity(rings);
+        for r in 0..rings {
+            let cells = match r {
+                0 => 1,
+                1 => width,
+                _ => {
+                    // the width of a cell of the previous ring along the
+                    // inside of this one, in units of the ring height
+                    let previous = widths[r - 1];
+                    let cell_width = 2.0 * PI * r as f64 / previous as f64;
+                    previous * (cell_width.round() as usize).max(1)
+                }
+            };
+            widths.push(cells);
+        }
+
+        let mut offsets = Vec::with_capacity(rings);
+        let mut total = 0;
+        for &cells in &widths {
+            offsets.push(total);
+            total += cells;
+        }
+
+        PolarGrid {
+            rings: widths,
+            offsets,
+        }
+    }
+
+    // getters
+
+    pub fn get_ring_count(&self) -> usize {
+        self.rings.len()
+    }
+
+    /// the number of cells in ring `r`
+    pub fn get_ring_width(&self, r: usize) -> usize {
+        self.rings[r]
+    }
+
+    /// the total number of cells
+    pub fn size(&self) -> usize {
+        self.offsets
+            .last()
+            .map_or(0, |&offset| offset + self.rings[self.rings.len() - 1])
+    }
+
+    /// the vertex of cell `c` of ring `r`
+    pub fn get_vertex(&self, r: usize, c: usize) -> usize {
+        self.offsets[r] + c
+    }
+
+    /// the ring of the given vertex, and its position in the ring
+    pub fn get_position(&self, v: usize) -> (usize, usize) {
+        let r = match self.offsets.binary_search(&v) {
+            Ok(r) => r,
+            Err(r) => r - 1,
+        };
+
+        (r, v - self.offsets[r])
+    }
+
+    /// the number of cells of ring `r + 1` lying outside each cell of ring `r`
+    pub fn get_ratio(&self, r: usize) -> usize {
+        self.rings[r + 1] / self.rings[r]
+    }
+
+    /// the cell inside the given cell, which the centre lacks
+    pub fn inward(&self, v: usize) -> Option<usize> {
+        let (r, c) = self.get_position(v);
+
+        if r == 0 {
+            None
+        } else {
+            Some(self.get_vertex(r - 1, c / self.get_ratio(r - 1)))
+        }
+    }
+
+    /// the cells outside the given cell, which the outermost ring lacks
+    pub fn outward(&self, v: usize) -> Range<usize> {
+        let (r, c) = self.get_position(v);
+
+        if r + 1 == self.rings.len() {
+            return v..v;
+        }
+
+        let ratio = self.get_ratio(r);
+        let first = self.get_vertex(r + 1, c * ratio);
+        first..first + ratio
+    }
+
+    /// the next cell clockwise around the ring, which a ring of a single
+    /// cell lacks
+    pub fn clockwise(&self, v: usize) -> Option<usize> {
+        let (r, c) = self.get_position(v);
+        let cells = self.rings[r];
+
+        Some(self.get_vertex(r, (c + 1) % cells)).filter(|_| cells > 1)
+    }
+
+    /// the next cell counter-clockwise around the ring, which a ring of a
+    /// single cell lacks
+    pub fn counter_clockwise(&self, v: usize) -> Option<usize> {
+        let (r, c) = self.get_position(v);
+        let cells = self.rings[r];
+
+        Some(self.get_vertex(r, (c + cells - 1) % cells)).filter(|_| cells > 1)
+    }
+
+    /// all the cells neighbouring the given cell, in increasing order
+    pub fn get_neighbours(&self, v: usize) -> Vec<usize> {
+        let mut neighbours: Vec<usize> = self
+            .inward(v)
+            .into_iter()
+            .chain(self.counter_clockwise(v))
+            .chain(self.clockwise(v))
+            .chain(self.outward(v))
+            .collect();
+        neighbours.sort_unstable();
+        neighbours.dedup();
+
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_widths() {
+        let grid = PolarGrid::new(7, 6);
+
+        let widths: Vec<usize> = (0..grid.get_ring_count())
+            .map(|r| grid.get_ring_width(r))
+            .collect();
+        assert_eq!(widths, vec![1, 6, 12, 24, 24, 24, 48]);
+        assert_eq!(grid.size(), 139);
+        assert_eq!(grid.get_position(138), (6, 47));
+        assert_eq!(grid.get_position(7), (2, 0));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = PolarGrid::new(4, 6);
+
+        // the centre is surrounded by the first ring
+        assert_eq!(grid.get_neighbours(0), vec![1, 2, 3, 4, 5, 6]);
+
+        // the first cell of the first ring wraps around to the last
+        assert_eq!(grid.inward(1), Some(0));
+        assert_eq!(grid.counter_clockwise(1), Some(6));
+        assert_eq!(grid.clockwise(1), Some(2));
+        assert_eq!(grid.outward(1), 7..9);
+
+        // the second ring splits each cell of the first in two
+        let v = grid.get_vertex(2, 5);
+        assert_eq!(grid.inward(v), Some(grid.get_vertex(1, 2)));
+        assert_eq!(grid.get_neighbours(v), vec![3, 11, 13, 29, 30]);
+
+        // the outermost ring leads nowhere further out
+        assert!(grid.outward(grid.size() - 1).is_empty());
+    }
+
+    #[test]
+    fn test_small_rings() {
+        // a ring of two cells has the same cell either way round
+        let grid = PolarGrid::new(2, 2);
+        assert_eq!(grid.get_neighbours(1), vec![0, 2]);
+
+        let grid = PolarGrid::new(2, 1);
+        assert_eq!(grid.get_neighbours(1), vec![0]);
+        assert_eq!(grid.clockwise(1), None);
+
+        assert_eq!(PolarGrid::new(1, 6).size(), 1);
+    }
+}
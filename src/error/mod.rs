@@ -26,6 +26,51 @@ pub enum ErrorKind {
     /// Provided vertex/vertices was/were
     /// invalid
     InvalidVertexOrVertices,
+
+    /// The operation is not defined for the
+    /// type of the graph
+    InvalidGraphType,
+
+    /// Some vertices of the graph cannot be
+    /// reached from the others
+    DisconnectedGraph,
+
+    /// Provided maze generation algorithm (or its
+    /// options) was not recognised
+    InvalidGenerator,
+
+    /// Provided seed was not a non-negative
+    /// integer
+    InvalidSeed,
+
+    /// Provided braid fraction was not a number
+    /// between 0 and 1
+    InvalidBraid,
+
+    /// Provided horizontal bias or straightness was
+    /// not a number between 0 and 1
+    InvalidTexture,
+
+    /// Provided tiling was not recognised, or is
+    /// not supported by the maze generator
+    InvalidTiling,
+
+    /// The output file could not be
+    /// written
+    InvalidOutputFile,
+
+    /// The maze can only be
+    /// drawn as an image
+    MissingOutputFile,
+
+    /// Provided number of levels was not a positive
+    /// integer, or the level does not exist, or the maze
+    /// cannot have more than one level
+    InvalidLevels,
+
+    /// Provided wrap was not recognised, or the maze
+    /// does not have square cells on a single level
+    InvalidWrap,
 }
 
 impl ErrorKind {
@@ -34,6 +79,23 @@ impl ErrorKind {
             ErrorKind::InvalidDimensionsNotNumber => "invalid dimensions: non-numeric values",
             ErrorKind::InvalidDimensions => "invalid dimensions: non (positive) integer values",
             ErrorKind::InvalidVertexOrVertices => "invalid vertex or vertices",
+            ErrorKind::InvalidGraphType => "invalid graph type: not defined for directed graphs",
+            ErrorKind::DisconnectedGraph => "disconnected graph: some cells cannot be reached",
+            ErrorKind::InvalidGenerator => "invalid generator: unknown algorithm or options",
+            ErrorKind::InvalidSeed => "invalid seed: not a non-negative integer",
+            ErrorKind::InvalidBraid => "invalid braid: not a number between 0 and 1",
+            ErrorKind::InvalidTexture => "invalid texture: not a number between 0 and 1",
+            ErrorKind::InvalidTiling => "invalid tiling: unknown or unsupported by the generator",
+            ErrorKind::InvalidOutputFile => "invalid output file: could not be written",
+            ErrorKind::MissingOutputFile => {
+                "missing output file: polar mazes can only be drawn with --svg FILE"
+            }
+            ErrorKind::InvalidLevels => {
+                "invalid levels: not a positive integer, or unsupported by the tiling, generator or --svg"
+            }
+            ErrorKind::InvalidWrap => {
+                "invalid wrap: unknown, or the maze does not have square cells on a single level"
+            }
         }
     }
 }
@@ -70,7 +132,7 @@ impl MazeError {
     {
         MazeError {
             repr: Repr::Custom(Box::new(Custom {
-                kind: kind,
+                kind,
                 error: error.into(),
             })),
         }
@@ -83,6 +145,14 @@ impl MazeError {
             repr: Repr::Simple(kind),
         }
     }
+
+    /// the category of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self.repr {
+            Repr::Simple(kind) => kind,
+            Repr::Custom(ref custom_error) => custom_error.kind,
+        }
+    }
 }
 
 /// make this custom error type an actual error type that can be used
@@ -2,32 +2,33 @@
 //! generating the maze, as well as solving it.
 
 pub mod renderer;
+pub mod svg;
 
-///
-/// ANSI Escaoe Codes
-///
+//
+// ANSI Escaoe Codes
+//
 
 /// clear screen
-pub static CLS: &'static str = "\x1B[2J";
+pub static CLS: &str = "\x1B[2J";
 
 /// erase the current line
-pub static CLEAR_CURRENT_LINE: &'static str = "\x1B[2K";
+pub static CLEAR_CURRENT_LINE: &str = "\x1B[2K";
 
 /// move cursor one position back
-pub static MOVE_BACK_ONE_LINE: &'static str = "\x1B[F";
+pub static MOVE_BACK_ONE_LINE: &str = "\x1B[F";
 
 /// save the cursor's current location in the terminal's buffer
-pub static SAVE_CURSOR_POSITION: &'static str = "\x1B[s";
+pub static SAVE_CURSOR_POSITION: &str = "\x1B[s";
 
 /// restore the cursor to the last saved cursor location
-pub static RESTORE_CURSOR_POSITION: &'static str = "\x1B[u";
+pub static RESTORE_CURSOR_POSITION: &str = "\x1B[u";
 
 /// reset font colour
-pub static RESET_COLOR: &'static str = "\x1B[0m";
+pub static RESET_COLOR: &str = "\x1B[0m";
 
-///
-/// constants used for rendering the cells
-///
+//
+// constants used for rendering the cells
+//
 
 /// initial location offset of the column cursor (from origin)
 pub const COL_INIT: usize = 3;
@@ -39,21 +40,51 @@ pub const LINE_INIT: usize = 2;
 /// line increment for cell sprite
 pub const LINE_OFFSET: usize = 1;
 
+/// line increment between rows of hexagonal cells, which share their
+/// diagonal walls with the rows above and below
+pub const HEX_LINE_OFFSET: usize = 2;
+/// column increment between hexagonal cells
+pub const HEX_COL_OFFSET: usize = 4;
+/// column shift of every odd row of hexagonal cells
+pub const HEX_ROW_SHIFT: usize = 2;
+
 /// rendering (pause) speed of the cells
 pub const MAZE_ANIMATION_SPEED: u64 = 2; // ms
 /// rendering (pause) speed of the path through the maze
 pub const PATH_ANIMATION_SPEED: u64 = 150; // ms
 
 /// Sprites for rendering a cell in the maze
-pub static NORTH_SPRITE: &'static str = "+---+";
-pub static EAST_SPRITE: &'static str = "|";
-pub static SOUTH_SPRITE: &'static str = "+---+";
-pub static WEST_SPRITE: &'static str = "|";
+pub static NORTH_SPRITE: &str = "+---+";
+pub static EAST_SPRITE: &str = "|";
+pub static SOUTH_SPRITE: &str = "+---+";
+pub static WEST_SPRITE: &str = "|";
+
+/// Sprites for marking the stairs leading up, down, or both ways
+/// out of a cell of a multi-level maze
+pub const STAIRS_UP_SPRITE: char = 'U';
+pub const STAIRS_DOWN_SPRITE: char = 'D';
+pub const STAIRS_SPRITE: char = 'X';
+
+/// Sprites for rendering the diagonal walls of a hexagonal cell - the
+/// east and west walls are those of a square cell
+pub static HEX_NORTH_SPRITE: &str = "/ \\";
+pub static HEX_SOUTH_SPRITE: &str = "\\ /";
+
+/// the number of lines spanned by a triangular cell
+pub const TRIANGLE_HEIGHT: usize = 3;
+/// line increment between rows of triangular cells
+pub const TRIANGLE_LINE_OFFSET: usize = 3;
+/// column increment between triangular cells, which share their slopes
+pub const TRIANGLE_COL_OFFSET: usize = 3;
+
+/// Sprite for rendering the base of a triangular cell - the slopes are
+/// drawn one character per line
+pub static TRIANGLE_BASE_SPRITE: &str = "____";
 
 /// constants for font colours
-static RED_FONT: &'static str = "\x1B[31m";
-static GREEN_FONT: &'static str = "\x1B[32m";
-static BLUE_FONT: &'static str = "\x1B[34m";
+static RED_FONT: &str = "\x1B[31m";
+static GREEN_FONT: &str = "\x1B[32m";
+static BLUE_FONT: &str = "\x1B[34m";
 
 ///
 /// Enum representing various colours that may be
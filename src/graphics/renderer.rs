@@ -3,16 +3,14 @@
 //! appropriate location on the screen, and rendering the individual sprites
 //! forming that cell.
 
-///
-/// private helper functions that trigger the ANSI Escape Codes
-///
-
-/// clear the screen and place the cursor at the origin (top-left of
-/// the screen
-
-use super::*;
-use super::super::ds::{CellData, Direction, MazeData};
+use super::super::ds::{CellData, CellShape, Direction, MazeData};
+use super::super::helper;
 use super::super::io::flush;
+use super::*;
+
+//
+// private helper functions that trigger the ANSI Escape Codes
+//
 
 /// clear the terminal screen
 fn cls() {
@@ -26,7 +24,7 @@ fn newline() {
 
 /// go to a specific location on the screen
 fn locate(x: usize, y: usize) {
-    print!("{}", format!("\x1B[{};{}H", x + 1, y + 1));
+    print!("\x1B[{};{}H", x + 1, y + 1);
 }
 
 /// save the current position of the cursor on the screen -
@@ -63,9 +61,58 @@ fn pause(duration: u64) {
     flush();
 }
 
-///
-/// The public API
-///
+/// go to the diagonal wall of the hexagonal cell at
+/// the given location which faces the given direction
+fn locate_diagonal_wall(x: usize, y: usize, direction: &Direction) {
+    match direction {
+        Direction::NorthWest => locate(x, y + 1),
+        Direction::NorthEast => locate(x, y + 3),
+        Direction::SouthWest => locate(x + 2, y + 1),
+        _ => locate(x + 2, y + 3),
+    }
+}
+
+/// the screen positions of the characters forming the wall of the
+/// triangular cell at the given location which faces the given
+/// direction, along with the characters themselves. The base of
+/// a triangle pointing down lies on the line above its location.
+fn triangle_wall(
+    shape: CellShape,
+    x: usize,
+    y: usize,
+    direction: &Direction,
+) -> Vec<(usize, usize, char)> {
+    let slope = |start: (usize, usize), step: isize, c: char| {
+        (0..TRIANGLE_HEIGHT)
+            .map(|k| {
+                (
+                    start.0 + k,
+                    (start.1 as isize + step * k as isize) as usize,
+                    c,
+                )
+            })
+            .collect()
+    };
+    let base = |x: usize| {
+        (1..=TRIANGLE_BASE_SPRITE.len())
+            .map(|k| (x, y + k, '_'))
+            .collect()
+    };
+
+    match (shape, direction) {
+        (CellShape::UpTriangle, Direction::West) => slope((x, y + 2), -1, '/'),
+        (CellShape::UpTriangle, Direction::East) => slope((x, y + 3), 1, '\\'),
+        (CellShape::UpTriangle, Direction::South) => base(x + 2),
+        (CellShape::DownTriangle, Direction::West) => slope((x, y), 1, '\\'),
+        (CellShape::DownTriangle, Direction::East) => slope((x, y + 5), -1, '/'),
+        (CellShape::DownTriangle, Direction::North) => base(x - 1),
+        _ => Vec::new(),
+    }
+}
+
+//
+// The public API
+//
 
 /// Render the given maze on the screen
 /// using ANSI Escape Codes
@@ -77,9 +124,16 @@ pub fn draw_maze(maze: &MazeData) {
     locate(0, 0);
 
     for i in 0..maze.get_height() {
-        for j in 0..maze.get_width() {
+        for j in 0..maze.get_row_width(i) {
             pause(MAZE_ANIMATION_SPEED);
-            draw_cell(maze.get_cell(i, j));
+            let cell = maze.get_cell(i, j);
+            match cell.get_shape() {
+                CellShape::Square => draw_cell(cell),
+                CellShape::Hexagon => draw_hex_cell(cell),
+                CellShape::UpTriangle | CellShape::DownTriangle => draw_triangle_cell(cell),
+                // the curved walls of a sector are only drawn as an image
+                CellShape::Sector => {}
+            }
         }
     }
 
@@ -105,6 +159,44 @@ pub fn draw_cell(cell: &CellData) {
     print!("{}", SOUTH_SPRITE);
 }
 
+/// Render a single hexagonal cell on the screen by rendering
+/// the sprites corresponding to its six walls. The diagonal
+/// walls are shared with the rows above and below.
+pub fn draw_hex_cell(cell: &CellData) {
+    let (x, y) = (cell.get_location().get_x(), cell.get_location().get_y());
+
+    locate(x, y + 1);
+    print!("{}", HEX_NORTH_SPRITE);
+
+    locate(x + 1, y);
+    print!("{}", WEST_SPRITE);
+
+    locate(x + 1, y + 4);
+    print!("{}", EAST_SPRITE);
+
+    locate(x + 2, y + 1);
+    print!("{}", HEX_SOUTH_SPRITE);
+}
+
+/// Render a single triangular cell on the screen by rendering
+/// its three walls - the slopes are shared with the cells to
+/// the east and west, and the base with the row above or below.
+pub fn draw_triangle_cell(cell: &CellData) {
+    let (x, y) = (cell.get_location().get_x(), cell.get_location().get_y());
+
+    for direction in &[
+        Direction::North,
+        Direction::West,
+        Direction::East,
+        Direction::South,
+    ] {
+        for (x, y, c) in triangle_wall(cell.get_shape(), x, y, direction) {
+            locate(x, y);
+            print!("{}", c);
+        }
+    }
+}
+
 /// adjust the menu location by erasing lines
 /// and moving cursor back
 pub fn delete_current_line() {
@@ -122,31 +214,75 @@ pub fn erase_wall(cell: &CellData, direction: &Direction) {
 
     let (x, y) = (cell.get_location().get_x(), cell.get_location().get_y());
 
+    if let CellShape::UpTriangle | CellShape::DownTriangle = cell.get_shape() {
+        for (x, y, _) in triangle_wall(cell.get_shape(), x, y, direction) {
+            locate(x, y);
+            print!(" ");
+        }
+
+        restore_cursor_position();
+        return;
+    }
+
     match direction {
         Direction::North => {
             locate(x, y + 1);
             for _ in 0..NORTH_SPRITE.len() - 2 {
-                print!("{}", ' ');
+                print!(" ");
             }
         }
 
         Direction::South => {
             locate(x + 2, y + 1);
             for _ in 0..SOUTH_SPRITE.len() - 2 {
-                print!("{}", ' ');
+                print!(" ");
             }
         }
 
         Direction::East => {
             locate(x + 1, y + 4);
-            print!("{}", ' ');
+            print!(" ");
         }
 
         Direction::West => {
             locate(x + 1, y);
-            print!("{}", ' ');
+            print!(" ");
+        }
+
+        // the diagonal walls of a hexagonal cell
+        Direction::NorthEast
+        | Direction::NorthWest
+        | Direction::SouthEast
+        | Direction::SouthWest => {
+            locate_diagonal_wall(x, y, direction);
+            print!(" ");
         }
+
+        // stairs lead out through the floor or the ceiling, which are
+        // marked by `mark_stairs` instead
+        Direction::Up | Direction::Down => {}
+    }
+
+    restore_cursor_position();
+}
+
+/// Erase the appropriate wall of the given cell, and mark the
+/// doorway with the direction in which it may be passed through,
+/// for passages that only lead one way.
+pub fn mark_one_way(cell: &CellData, direction: &Direction) {
+    erase_wall(cell, direction);
+    save_cursor_position();
+
+    let (x, y) = (cell.get_location().get_x(), cell.get_location().get_y());
+
+    match direction {
+        Direction::North => locate(x, y + 2),
+        Direction::South => locate(x + 2, y + 2),
+        Direction::East => locate(x + 1, y + 4),
+        Direction::West => locate(x + 1, y),
+        _ => locate_diagonal_wall(x, y, direction),
     }
+    print!("{}", helper::get_char_for_direction(direction));
 
     restore_cursor_position();
 }
@@ -169,6 +305,19 @@ pub fn fill_cell(cell: &CellData, c: char) {
     restore_cursor_position();
 }
 
+/// mark the given cell of a multi-level maze with
+/// the stairs leading out of it
+pub fn mark_stairs(cell: &CellData, c: char) {
+    save_cursor_position();
+
+    let (x, y) = (cell.get_location().get_x(), cell.get_location().get_y());
+
+    locate(x + 1, y + 2);
+    print!("{}", c);
+
+    restore_cursor_position();
+}
+
 /// clear the contents of the current cell
 /// so that the maze can be animated again
 pub fn clear_cell(cell: &CellData) {
@@ -177,7 +326,7 @@ pub fn clear_cell(cell: &CellData) {
     let (x, y) = (cell.get_location().get_x(), cell.get_location().get_y());
 
     locate(x + 1, y + 2);
-    print!("{}", ' ');
+    print!(" ");
 
     restore_cursor_position();
 }
@@ -0,0 +1,440 @@
+//! This module renders mazes as SVG images. Unlike the terminal, an image can
+//! draw cells of any shape, so every cell is simply a polygon (or, in a polar
+//! maze, a sector of a ring bounded by arcs), and a wall is drawn along every
+//! side of a cell which is not crossed by a passage.
+
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use super::super::ds::graphs::Graph;
+use super::super::ds::{CellShape, MazeData, Tiling};
+
+/// the length of the side of a cell, in pixels
+const SIDE: f64 = 20.0;
+/// the blank space around the maze, in pixels
+const MARGIN: f64 = 10.0;
+
+/// the colour of the walls of the maze
+static WALL_COLOUR: &str = "black";
+/// the colour of the path through the maze
+static PATH_COLOUR: &str = "red";
+
+/// a point of the image
+type Vertex = (f64, f64);
+
+/// a side of a cell - a straight line, or a clockwise arc of the given
+/// radius around the centre of a polar maze
+#[derive(Debug, Copy, Clone)]
+struct Side {
+    from: Vertex,
+    to: Vertex,
+    radius: Option<f64>,
+}
+
+/// round the given point to the hundredths of a pixel written to the image
+fn round((x, y): Vertex) -> Vertex {
+    let round = |x: f64| (x * 100.0).round() / 100.0 + 0.0;
+
+    (round(x), round(y))
+}
+
+/// the corners of cell `(i, j)`, in order around the cell
+fn polygon(shape: CellShape, i: usize, j: usize) -> Vec<Vertex> {
+    let (i, j) = (i as f64, j as f64);
+
+    let corners: Vec<Vertex> = match shape {
+        CellShape::Square => {
+            let (x, y) = (j * SIDE, i * SIDE);
+            vec![(x, y), (x + SIDE, y), (x + SIDE, y + SIDE), (x, y + SIDE)]
+        }
+
+        // pointy-topped, with every odd row shifted half a cell to the east
+        CellShape::Hexagon => {
+            let width = 3f64.sqrt() * SIDE;
+            let cx = width * (j + 0.5 + 0.5 * (i % 2.0));
+            let cy = SIDE * (1.0 + 1.5 * i);
+            (0..6)
+                .map(|k| {
+                    let angle = (60.0 * k as f64 - 90.0).to_radians();
+                    (cx + SIDE * angle.cos(), cy + SIDE * angle.sin())
+                })
+                .collect()
+        }
+
+        CellShape::UpTriangle | CellShape::DownTriangle => {
+            let height = 3f64.sqrt() / 2.0 * SIDE;
+            let (x, top, bottom) = (j * SIDE / 2.0, i * height, (i + 1.0) * height);
+            if shape == CellShape::UpTriangle {
+                vec![(x, bottom), (x + SIDE / 2.0, top), (x + SIDE, bottom)]
+            } else {
+                vec![(x, top), (x + SIDE, top), (x + SIDE / 2.0, bottom)]
+            }
+        }
+
+        CellShape::Sector => unreachable!("sectors are not polygons"),
+    };
+
+    corners.into_iter().map(round).collect()
+}
+
+/// the point at `radius` from the centre of a polar maze of `rings` rings,
+/// `index` cells of `count` clockwise from the east
+fn polar_point(rings: usize, radius: f64, index: usize, count: usize) -> Vertex {
+    let centre = rings as f64 * SIDE;
+    let angle = 2.0 * PI * index as f64 / count as f64;
+
+    round((centre + radius * angle.cos(), centre + radius * angle.sin()))
+}
+
+/// the arc at `radius` along cell `index` of a ring of `count` cells, split
+/// into arcs of at most a right angle, which SVG draws without ambiguity
+fn polar_arc(rings: usize, radius: f64, index: usize, count: usize) -> Vec<Side> {
+    let pieces = (4 + count - 1) / count;
+
+    (0..pieces)
+        .map(|k| {
+            let (index, count) = (index * pieces + k, count * pieces);
+            Side {
+                from: polar_point(rings, radius, index, count),
+                to: polar_point(rings, radius, index + 1, count),
+                radius: Some(radius),
+            }
+        })
+        .collect()
+}
+
+/// the sides of sector `(r, c)` of a polar maze - its inner arc, the lines
+/// between it and its neighbours around the ring, and an outer arc for each
+/// cell of the next ring out - along with its centre
+fn sector(cells: &MazeData, r: usize, c: usize) -> (Vec<Side>, Vertex) {
+    let rings = cells.get_height();
+    let count = cells.get_row_width(r);
+    let (inner, outer) = (r as f64 * SIDE, (r + 1) as f64 * SIDE);
+
+    let mut sides = Vec::new();
+    if r > 0 {
+        sides.extend(polar_arc(rings, inner, c, count));
+    }
+
+    if count > 1 {
+        for &index in &[c, c + 1] {
+            sides.push(Side {
+                from: polar_point(rings, inner, index, count),
+                to: polar_point(rings, outer, index, count),
+                radius: None,
+            });
+        }
+    }
+
+    if r + 1 < rings {
+        let ratio = cells.get_row_width(r + 1) / count;
+        for k in 0..ratio {
+            sides.extend(polar_arc(rings, outer, c * ratio + k, count * ratio));
+        }
+    } else {
+        sides.extend(polar_arc(rings, outer, c, count));
+    }
+
+    let centre = if r == 0 {
+        polar_point(rings, 0.0, 0, 1)
+    } else {
+        polar_point(rings, inner + SIDE / 2.0, 2 * c + 1, 2 * count)
+    };
+
+    (sides, centre)
+}
+
+/// the sides of cell `(i, j)`, along with its centre
+fn outline(cells: &MazeData, i: usize, j: usize) -> (Vec<Side>, Vertex) {
+    let shape = cells.get_cell(i, j).get_shape();
+    if shape == CellShape::Sector {
+        return sector(cells, i, j);
+    }
+
+    let polygon = polygon(shape, i, j);
+    let sides = (0..polygon.len())
+        .map(|k| Side {
+            from: polygon[k],
+            to: polygon[(k + 1) % polygon.len()],
+            radius: None,
+        })
+        .collect();
+
+    (sides, centre(&polygon))
+}
+
+/// the centre of the given polygon
+fn centre(polygon: &[Vertex]) -> Vertex {
+    let n = polygon.len() as f64;
+    let (x, y) = polygon
+        .iter()
+        .fold((0.0, 0.0), |(x, y), &(px, py)| (x + px, y + py));
+
+    (x / n, y / n)
+}
+
+/// a side of a cell which can be compared with the sides of the other cells,
+/// regardless of the order of its ends
+fn side_key(from: Vertex, to: Vertex) -> ((i64, i64), (i64, i64)) {
+    let scale = |(x, y): Vertex| ((x * 100.0).round() as i64, (y * 100.0).round() as i64);
+    let (from, to) = (scale(from), scale(to));
+
+    (from.min(to), from.max(to))
+}
+
+///
+/// Render the maze, with the passages of `maze`, as an SVG image, along with
+/// the given path through it (which may be empty).
+///
+pub fn render(cells: &MazeData, maze: &dyn Graph, path: &[usize]) -> String {
+    let mut outlines = Vec::new();
+    for i in 0..cells.get_height() {
+        for j in 0..cells.get_row_width(i) {
+            outlines.push(outline(cells, i, j));
+        }
+    }
+
+    // the east and south edges of a maze which wraps around are the same as
+    // the west and north edges, so the sides along them are moved across to
+    // be compared with the sides of the cells at the opposite edge
+    let wrap = cells.get_wrap();
+    let (h, w) = (cells.get_height(), cells.get_width());
+    let (east, south) = (w as f64 * SIDE, h as f64 * SIDE);
+    let wrapped_key = |side: &Side| {
+        let (mut from, mut to) = (side.from, side.to);
+        if wrap.wraps_horizontally(w) && from.0 == east && to.0 == east {
+            from.0 = 0.0;
+            to.0 = 0.0;
+        }
+        if wrap.wraps_vertically(h) && from.1 == south && to.1 == south {
+            from.1 = 0.0;
+            to.1 = 0.0;
+        }
+        side_key(from, to)
+    };
+
+    // the sides of neighbouring cells coincide, so each side is owned by one
+    // cell on the boundary of the maze, or by the two cells it separates -
+    // and is drawn twice if it separates cells at opposite edges
+    let mut sides: HashMap<_, (Vec<Side>, Vec<usize>)> = HashMap::new();
+    for (v, (outline, _)) in outlines.iter().enumerate() {
+        for &side in outline {
+            let (drawn, owners) = sides.entry(wrapped_key(&side)).or_default();
+            let key = side_key(side.from, side.to);
+            if drawn
+                .iter()
+                .all(|other| side_key(other.from, other.to) != key)
+            {
+                drawn.push(side);
+            }
+            owners.push(v);
+        }
+    }
+
+    let mut walls: Vec<_> = sides
+        .into_iter()
+        .filter(|(_, (_, owners))| match owners[..] {
+            [v1, v2] => !maze.has_edge(v1, v2) && !maze.has_edge(v2, v1),
+            _ => true,
+        })
+        .map(|(key, (drawn, _))| (key, drawn))
+        .collect();
+    // in a fixed order, so that the same maze always gives the same image
+    walls.sort_by_key(|(key, _)| *key);
+
+    // the outermost ring of a polar maze bulges out beyond the ends of its
+    // arcs, to the edges of a square around the circle
+    let (mut width, mut height) = match cells.get_tiling() {
+        Tiling::Polar => {
+            let diameter = 2.0 * cells.get_height() as f64 * SIDE;
+            (diameter, diameter)
+        }
+        _ => (0.0f64, 0.0f64),
+    };
+    for side in outlines.iter().flat_map(|(outline, _)| outline) {
+        for &(x, y) in &[side.from, side.to] {
+            width = width.max(x);
+            height = height.max(y);
+        }
+    }
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\">",
+        width + 2.0 * MARGIN,
+        height + 2.0 * MARGIN
+    );
+    let _ = writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>");
+    let _ = writeln!(svg, "<g transform=\"translate({0},{0})\">", MARGIN);
+
+    let mut d = String::new();
+    for side in walls.into_iter().flat_map(|(_, drawn)| drawn) {
+        let ((x1, y1), (x2, y2)) = (side.from, side.to);
+        let _ = write!(d, "M{:.2} {:.2}", x1, y1);
+        let _ = match side.radius {
+            Some(r) => write!(d, "A{:.2} {:.2} 0 0 1 {:.2} {:.2}", r, r, x2, y2),
+            None => write!(d, "L{:.2} {:.2}", x2, y2),
+        };
+    }
+    let _ = writeln!(
+        svg,
+        "<path d=\"{}\" stroke=\"{}\" stroke-width=\"2\" stroke-linecap=\"round\" fill=\"none\"/>",
+        d, WALL_COLOUR
+    );
+
+    // the path is broken wherever it leads around from one edge of the maze
+    // to the other, running out to the edge on either side
+    let mut runs: Vec<Vec<Vertex>> = Vec::new();
+    for (k, &v) in path.iter().enumerate() {
+        let (x, y) = outlines[v].1;
+        if k == 0 {
+            runs.push(vec![(x, y)]);
+            continue;
+        }
+
+        let (px, py) = outlines[path[k - 1]].1;
+        let step = |from: f64, to: f64, wraps: bool| match to - from {
+            d if wraps && d > 1.5 * SIDE => -SIDE / 2.0,
+            d if wraps && d < -1.5 * SIDE => SIDE / 2.0,
+            _ => 0.0,
+        };
+        let (dx, dy) = (
+            step(px, x, wrap.wraps_horizontally(w)),
+            step(py, y, wrap.wraps_vertically(h)),
+        );
+
+        if dx == 0.0 && dy == 0.0 {
+            runs.last_mut().unwrap().push((x, y));
+        } else {
+            runs.last_mut().unwrap().push((px + dx, py + dy));
+            runs.push(vec![(x - dx, y - dy), (x, y)]);
+        }
+    }
+
+    for run in runs {
+        let points: Vec<String> = run
+            .iter()
+            .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+            .collect();
+        let _ = writeln!(
+            svg,
+            "<polyline points=\"{}\" stroke=\"{}\" stroke-width=\"2\" fill=\"none\"/>",
+            points.join(" "),
+            PATH_COLOUR
+        );
+    }
+
+    let _ = writeln!(svg, "</g>");
+    let _ = writeln!(svg, "</svg>");
+
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::graphs::{AdjacencySet, GraphType};
+    use crate::ds::{CellData, Point, Tiling, Wrap};
+
+    fn maze_data(h: usize, w: usize, tiling: Tiling) -> MazeData {
+        let mut id = 0;
+        let cells = tiling
+            .get_row_widths(h, w)
+            .into_iter()
+            .enumerate()
+            .map(|(i, row_width)| {
+                (0..row_width)
+                    .map(|j| {
+                        id += 1;
+                        CellData::with_shape(id - 1, Point::new(i, j), tiling.get_cell_shape(i, j))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        MazeData::with_tiling(h, w, tiling, cells)
+    }
+
+    /// the number of walls drawn in the image
+    fn count_walls(svg: &str) -> usize {
+        svg.matches('M').count()
+    }
+
+    #[test]
+    fn test_render_square() {
+        let cells = maze_data(1, 2, Tiling::Square);
+
+        let closed = render(&cells, &AdjacencySet::new(2, GraphType::UNDIRECTED), &[]);
+        assert_eq!(count_walls(&closed), 7);
+        assert!(!closed.contains("polyline"));
+
+        let open = render(&cells, &AdjacencySet::grid(1, 2), &[0, 1]);
+        assert_eq!(count_walls(&open), 6);
+        assert!(open.contains("<polyline points=\"10.00,10.00 30.00,10.00\""));
+    }
+
+    #[test]
+    fn test_render_shared_sides() {
+        // every passage of the grid removes exactly one wall, so that all
+        // the sides between neighbouring cells are found to be shared
+        for &tiling in &[Tiling::Square, Tiling::Hex, Tiling::Delta, Tiling::Polar] {
+            let (h, w) = (3, 4);
+            let cells = maze_data(h, w, tiling);
+            let grid = AdjacencySet::tiled_grid(h, w, tiling, GraphType::UNDIRECTED);
+
+            let closed = render(
+                &cells,
+                &AdjacencySet::new(grid.size(), GraphType::UNDIRECTED),
+                &[],
+            );
+            let open = render(&cells, &grid, &[]);
+
+            assert_eq!(count_walls(&closed) - count_walls(&open), grid.edge_count());
+        }
+    }
+
+    #[test]
+    fn test_render_wrapped() {
+        let mut cells = maze_data(3, 3, Tiling::Square);
+        cells.set_wrap(Wrap::Horizontal);
+
+        // the walls along the east and west edges are still drawn on both
+        let closed = render(&cells, &AdjacencySet::new(9, GraphType::UNDIRECTED), &[]);
+        assert_eq!(count_walls(&closed), 24);
+
+        // but are opened by the passages leading around from one to the other
+        let grid = AdjacencySet::wrapped_grid(3, 3, Wrap::Horizontal, GraphType::UNDIRECTED);
+        let open = render(&cells, &grid, &[]);
+        assert_eq!(count_walls(&open), 6);
+        for wall in &["M0.00 20.00L0.00 0.00", "M60.00 0.00L60.00 20.00"] {
+            assert!(closed.contains(wall) && !open.contains(wall));
+        }
+
+        // the path around from the first cell to the last leaves by the west
+        // edge and comes back in by the east
+        let open = render(&cells, &grid, &[0, 2]);
+        assert!(open.contains("<polyline points=\"10.00,10.00 0.00,10.00\""));
+        assert!(open.contains("<polyline points=\"60.00,10.00 50.00,10.00\""));
+    }
+
+    #[test]
+    fn test_render_polar() {
+        // a centre surrounded by a single ring of two cells
+        let cells = maze_data(2, 2, Tiling::Polar);
+        let closed = render(&cells, &AdjacencySet::new(3, GraphType::UNDIRECTED), &[]);
+
+        // both circles are drawn as four quarters, along with the two lines
+        // between the cells of the ring
+        assert_eq!(count_walls(&closed), 4 + 2 + 4);
+        assert!(closed.contains("width=\"100\" height=\"100\""));
+        assert!(closed.contains("M60.00 40.00A20.00 20.00 0 0 1 40.00 60.00"));
+
+        let grid = AdjacencySet::tiled_grid(2, 2, Tiling::Polar, GraphType::UNDIRECTED);
+        let open = render(&cells, &grid, &[0, 1]);
+        // the two cells of the ring are joined on either side
+        assert_eq!(count_walls(&open), 4);
+        assert!(open.contains("<polyline points=\"40.00,40.00 40.00,70.00\""));
+    }
+}
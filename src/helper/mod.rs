@@ -1,18 +1,15 @@
 //! This module contains common helper functions.
 
-// import the `rand` crate since Rust's standard library does not have
-// support for random number generation
-
-use rand::prelude::*;
-
 use crate::error::*;
+use std::cmp::Ordering;
 use std::str::FromStr;
 
-use crate::ds::{CellData, Direction};
+use crate::ds::graphs::generators::{self, Bias, MazeGenerator, SelectionPolicy, Texture};
+use crate::ds::{CellData, Direction, Tiling, Wrap};
 
 /// get the maze's height and width from the command-line arguments
 /// with suitable validation
-pub fn get_maze_dimensions(args: &Vec<String>) -> Result<(usize, usize)> {
+pub fn get_maze_dimensions(args: &[String]) -> Result<(usize, usize)> {
     if let Ok(h) = usize::from_str(args[0].trim()) {
         if let Ok(w) = usize::from_str(args[1].trim()) {
             if h < 1 || w < 1 {
@@ -24,35 +21,179 @@ pub fn get_maze_dimensions(args: &Vec<String>) -> Result<(usize, usize)> {
     Err(MazeError::of(ErrorKind::InvalidDimensionsNotNumber))
 }
 
-/// Return a random number in the closed range [l, h]
-/// This uses the `rand` crate to generate PRNs.
-pub fn get_random_number_in_range(l: isize, h: isize) -> isize {
-    let mut rng = thread_rng();
+/// get the maze generation algorithm from its name on the command line. The
+/// Growing Tree Algorithm may be followed by its selection policy, as in
+/// `growing-tree:newest=75,random=25`, and the Binary Tree and Sidewinder
+/// Algorithms by their bias, as in `sidewinder:sw`.
+///
+/// `texture` is the horizontal bias and straightness (if any) of the
+/// passages, which only Prim's Algorithm and the depth-first style
+/// generators support.
+pub fn get_generator(
+    name: &str,
+    height: usize,
+    width: usize,
+    texture: Option<(f64, Option<f64>)>,
+) -> Result<Box<dyn MazeGenerator>> {
+    let mut pieces = name.trim().splitn(2, ':');
+    let algorithm = pieces.next().unwrap_or("");
+    let options = pieces.next();
+
+    let texture = texture.map(|(bias, straightness)| match straightness {
+        Some(straightness) => Texture::new(width, bias, straightness),
+        None => Texture::with_bias(width, bias),
+    });
+
+    let generator: Box<dyn MazeGenerator> = match (algorithm, options) {
+        ("prim", None) => {
+            Box::new(texture.map_or_else(generators::Prim::default, generators::Prim::with_texture))
+        }
+        ("backtracker", None) => Box::new(texture.map_or_else(
+            generators::RecursiveBacktracker::default,
+            generators::RecursiveBacktracker::with_texture,
+        )),
+        ("hunt-and-kill", None) => Box::new(texture.map_or_else(
+            generators::HuntAndKill::default,
+            generators::HuntAndKill::with_texture,
+        )),
+        ("growing-tree", policy) => {
+            let policy = policy.map_or(Ok(SelectionPolicy::Newest), SelectionPolicy::from_str)?;
+            Box::new(match texture {
+                Some(texture) => generators::GrowingTree::with_texture(policy, texture),
+                None => generators::GrowingTree::new(policy),
+            })
+        }
+        // the remaining generators do not support a texture
+        _ if texture.is_some() => return Err(MazeError::of(ErrorKind::InvalidGenerator)),
+        ("kruskal", None) => Box::new(generators::Kruskal),
+        ("wilson", None) => Box::new(generators::Wilson),
+        ("aldous-broder", None) => Box::new(generators::AldousBroder),
+        ("recursive-division", None) => Box::new(generators::RecursiveDivision::new(height, width)),
+        ("binary-tree", bias) => Box::new(generators::BinaryTree::new(
+            height,
+            width,
+            bias.map_or(Ok(Bias::default()), Bias::from_str)?,
+        )),
+        ("sidewinder", bias) => Box::new(generators::Sidewinder::new(
+            height,
+            width,
+            bias.map_or(Ok(Bias::default()), Bias::from_str)?,
+        )),
+        ("eller", None) => Box::new(generators::Eller::new(height, width)),
+        ("arborescence", None) => Box::new(generators::Arborescence::default()),
+        _ => return Err(MazeError::of(ErrorKind::InvalidGenerator)),
+    };
+
+    Ok(generator)
+}
+
+/// remove the `--seed N` option from the command-line arguments, if it is
+/// present, and return the seed
+pub fn take_seed(args: &mut Vec<String>) -> Result<Option<u64>> {
+    take_option(args, "--seed", ErrorKind::InvalidSeed)
+}
+
+/// remove the `--braid FRACTION` option from the command-line arguments, if
+/// it is present, and return the fraction of dead ends to remove
+pub fn take_braid(args: &mut Vec<String>) -> Result<Option<f64>> {
+    let fraction = take_option(args, "--braid", ErrorKind::InvalidBraid)?;
+
+    match fraction {
+        Some(f) if !(0.0..=1.0).contains(&f) => Err(MazeError::of(ErrorKind::InvalidBraid)),
+        _ => Ok(fraction),
+    }
+}
+
+/// remove the `--horizontal-bias BIAS` and `--straightness STRAIGHTNESS`
+/// options from the command-line arguments, if either is present, and return
+/// both (the bias defaulting to 0.5)
+pub fn take_texture(args: &mut Vec<String>) -> Result<Option<(f64, Option<f64>)>> {
+    let bias = take_option(args, "--horizontal-bias", ErrorKind::InvalidTexture)?;
+    let straightness = take_option(args, "--straightness", ErrorKind::InvalidTexture)?;
+
+    if bias.is_none() && straightness.is_none() {
+        return Ok(None);
+    }
+
+    let bias = bias.unwrap_or(0.5);
+    if !(0.0..=1.0).contains(&bias) || straightness.map_or(false, |s| !(0.0..=1.0).contains(&s)) {
+        return Err(MazeError::of(ErrorKind::InvalidTexture));
+    }
+
+    Ok(Some((bias, straightness)))
+}
+
+/// remove the `--tiling TILING` option from the command-line arguments, if
+/// it is present, and return the shape of the cells
+pub fn take_tiling(args: &mut Vec<String>) -> Result<Option<Tiling>> {
+    take_option(args, "--tiling", ErrorKind::InvalidTiling)
+}
+
+/// remove the `--wrap WRAP` option from the command-line arguments, if it is
+/// present, and return the edges of the maze which wrap around
+pub fn take_wrap(args: &mut Vec<String>) -> Result<Option<Wrap>> {
+    take_option(args, "--wrap", ErrorKind::InvalidWrap)
+}
+
+/// remove the `--levels LEVELS` option from the command-line arguments, if it
+/// is present, and return the number of levels of the maze
+pub fn take_levels(args: &mut Vec<String>) -> Result<Option<usize>> {
+    let levels = take_option(args, "--levels", ErrorKind::InvalidLevels)?;
+
+    match levels {
+        Some(0) => Err(MazeError::of(ErrorKind::InvalidLevels)),
+        _ => Ok(levels),
+    }
+}
+
+/// remove the `--svg FILE` option from the command-line arguments, if it is
+/// present, and return the name of the file to save the maze to
+pub fn take_svg(args: &mut Vec<String>) -> Result<Option<String>> {
+    take_option(args, "--svg", ErrorKind::InvalidOutputFile)
+}
+
+/// remove the option `name` and the value following it from the
+/// command-line arguments, and parse the value
+fn take_option<T: FromStr>(
+    args: &mut Vec<String>,
+    name: &str,
+    kind: ErrorKind,
+) -> Result<Option<T>> {
+    let position = match args.iter().position(|arg| arg == name) {
+        Some(position) => position,
+        None => return Ok(None),
+    };
+
+    let value = args
+        .get(position + 1)
+        .and_then(|value| T::from_str(value.trim()).ok())
+        .ok_or_else(|| MazeError::of(kind))?;
+    args.drain(position..position + 2);
 
-    rng.gen_range(l..h + 1)
+    Ok(Some(value))
 }
 
 /// find the relative direction of the source cell w.r.t the neighbouring
-/// cell by using their coordindates
+/// cell by using their coordindates - a neighbour in another row which is
+/// not directly above or below is diagonal, as in a hexagonal maze. This
+/// only depends on where the cells are drawn, so triangular cells, whose
+/// neighbours are beside them or directly above or below, need nothing more.
 pub fn get_direction(from: &CellData, to: &CellData) -> Direction {
     let fx = from.get_location().get_x();
     let fy = from.get_location().get_y();
     let tx = to.get_location().get_x();
     let ty = to.get_location().get_y();
 
-    if fx < tx {
-        return Direction::South;
-    }
-
-    if fx > tx {
-        return Direction::North;
+    match (fx.cmp(&tx), fy.cmp(&ty)) {
+        (Ordering::Less, Ordering::Equal) => Direction::South,
+        (Ordering::Less, Ordering::Less) => Direction::SouthEast,
+        (Ordering::Less, Ordering::Greater) => Direction::SouthWest,
+        (Ordering::Greater, Ordering::Equal) => Direction::North,
+        (Ordering::Greater, Ordering::Less) => Direction::NorthEast,
+        (Ordering::Greater, Ordering::Greater) => Direction::NorthWest,
+        (Ordering::Equal, Ordering::Less) => Direction::East,
+        (Ordering::Equal, _) => Direction::West,
     }
-
-    if fy < ty {
-        return Direction::East;
-    }
-
-    Direction::West
 }
 
 /// map the direction to the appropriate character for rendering
@@ -62,13 +203,119 @@ pub fn get_char_for_direction(direction: &Direction) -> char {
         Direction::South => 'v',
         Direction::East => '>',
         Direction::West => '<',
+        Direction::NorthEast | Direction::SouthWest => '/',
+        Direction::NorthWest | Direction::SouthEast => '\\',
+        Direction::Up => 'U',
+        Direction::Down => 'D',
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ds::Point;
+    use crate::ds::Point;
+
+    #[test]
+    fn test_take_seed() {
+        let mut args = vec![
+            "10".to_string(),
+            "--seed".to_string(),
+            "42".to_string(),
+            "20".to_string(),
+        ];
+
+        assert_eq!(take_seed(&mut args).unwrap(), Some(42));
+        assert_eq!(args, vec!["10".to_string(), "20".to_string()]);
+        assert_eq!(take_seed(&mut args).unwrap(), None);
+    }
+
+    #[test]
+    fn test_take_seed_invalid() {
+        let mut args = vec!["10".to_string(), "20".to_string(), "--seed".to_string()];
+        let err = take_seed(&mut args).err().unwrap();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidSeed);
+    }
+
+    #[test]
+    fn test_take_braid() {
+        let mut args = vec!["--braid".to_string(), "0.5".to_string()];
+        assert_eq!(take_braid(&mut args).unwrap(), Some(0.5));
+        assert!(args.is_empty());
+
+        let mut args = vec!["--braid".to_string(), "1.5".to_string()];
+        let err = take_braid(&mut args).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidBraid);
+    }
+
+    #[test]
+    fn test_take_texture() {
+        let mut args = vec!["--straightness".to_string(), "0.25".to_string()];
+        assert_eq!(take_texture(&mut args).unwrap(), Some((0.5, Some(0.25))));
+        assert_eq!(take_texture(&mut args).unwrap(), None);
+
+        let mut args = vec!["--horizontal-bias".to_string(), "2".to_string()];
+        let err = take_texture(&mut args).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidTexture);
+    }
+
+    #[test]
+    fn test_take_tiling() {
+        let mut args = vec!["--tiling".to_string(), "hex".to_string()];
+        assert_eq!(take_tiling(&mut args).unwrap(), Some(Tiling::Hex));
+        assert!(args.is_empty());
+
+        let mut args = vec!["--tiling".to_string(), "round".to_string()];
+        let err = take_tiling(&mut args).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidTiling);
+    }
+
+    #[test]
+    fn test_take_wrap() {
+        let mut args = vec!["--wrap".to_string(), "horizontal".to_string()];
+        assert_eq!(take_wrap(&mut args).unwrap(), Some(Wrap::Horizontal));
+        assert!(args.is_empty());
+
+        let mut args = vec!["--wrap".to_string(), "sideways".to_string()];
+        let err = take_wrap(&mut args).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidWrap);
+    }
+
+    #[test]
+    fn test_take_levels() {
+        let mut args = vec!["3".to_string(), "--levels".to_string(), "2".to_string()];
+        assert_eq!(take_levels(&mut args).unwrap(), Some(2));
+        assert_eq!(args, vec!["3".to_string()]);
+
+        for levels in &["0", "two"] {
+            let mut args = vec!["--levels".to_string(), levels.to_string()];
+            let err = take_levels(&mut args).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::InvalidLevels);
+        }
+    }
+
+    #[test]
+    fn test_get_generator() {
+        assert!(get_generator("prim", 2, 2, None).is_ok());
+        assert!(get_generator("growing-tree", 2, 2, None).is_ok());
+        assert!(get_generator("backtracker", 2, 2, Some((0.7, Some(0.5)))).is_ok());
+        assert!(get_generator("prim", 2, 2, Some((0.7, None))).is_ok());
+        assert!(get_generator("growing-tree:newest=75,random=25", 2, 2, None).is_ok());
+        assert!(get_generator("sidewinder", 2, 2, None).is_ok());
+        assert!(get_generator("binary-tree:sw", 2, 2, None).is_ok());
+        assert!(get_generator("arborescence", 2, 2, None).is_ok());
+    }
+
+    #[test]
+    fn test_get_generator_invalid() {
+        let err = get_generator("dijkstra", 2, 2, None).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidGenerator);
+
+        assert!(get_generator("prim:newest", 2, 2, None).is_err());
+        assert!(get_generator("growing-tree:latest", 2, 2, None).is_err());
+        assert!(get_generator("binary-tree:up", 2, 2, None).is_err());
+        assert!(get_generator("kruskal", 2, 2, Some((0.7, Some(0.5)))).is_err());
+    }
 
     #[test]
     fn test_get_direction_south() {
@@ -102,6 +349,17 @@ mod tests {
         assert_eq!(get_direction(&from, &to), Direction::North);
     }
 
+    #[test]
+    fn test_get_direction_diagonal() {
+        let from = CellData::new(0, Point::new(4, 7));
+
+        let to = CellData::new(1, Point::new(2, 5));
+        assert_eq!(get_direction(&from, &to), Direction::NorthWest);
+
+        let to = CellData::new(1, Point::new(6, 9));
+        assert_eq!(get_direction(&from, &to), Direction::SouthEast);
+    }
+
     #[test]
     fn test_char_for_direction_east() {
         let direction = Direction::East;
@@ -21,34 +21,31 @@ pub fn get_number() -> Option<usize> {
         .read_line(&mut input)
         .expect("failed to read input");
 
-    match usize::from_str(input.trim()) {
-        Ok(val) => Some(val),
-        Err(__) => None,
-    }
+    usize::from_str(input.trim()).ok()
 }
 
 /// print a message on standard output,
 /// with no newline
 pub fn print_message(message: &str) {
-    write!(io::stdout(), "{}", message).unwrap();
+    print!("{}", message);
     flush();
 }
 
 /// print a message on standard output, with a
 /// newline
 pub fn println_message(message: &str) {
-    writeln!(io::stdout(), "{}", message).unwrap();
+    println!("{}", message);
 }
 
 /// print a message on standard output, and quit
 /// immediately
-pub fn print_message_and_quit(message: &str) {
-    writeln!(io::stdout(), "{}", message).unwrap();
+pub fn print_message_and_quit(message: &str) -> ! {
+    println!("{}", message);
     ::std::process::exit(1);
 }
 
-pub fn print_error_and_quit(err: Box<MazeError>) {
-    writeln!(io::stdout(), "{}", err).unwrap();
+pub fn print_error_and_quit(err: Box<MazeError>) -> ! {
+    println!("{}", err);
     ::std::process::exit(1);
 }
 
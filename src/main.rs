@@ -1,39 +1,144 @@
 extern crate maze_rs;
 
 use maze_rs::core;
+use maze_rs::ds::{Tiling, Wrap};
+use maze_rs::error::{ErrorKind, MazeError};
 use maze_rs::helper;
 use maze_rs::io;
 
-static USAGE: &'static str = "Usage: cargo run HEIGHT WIDTH";
-static MENU: &'static str = "\nEnter choice (1 - solve, 2 - longest path, 3 - quit)... \n";
+static USAGE: &str = "Usage: cargo run HEIGHT WIDTH [GENERATOR] [--seed SEED] [--braid FRACTION]
+       [--horizontal-bias BIAS] [--straightness STRAIGHTNESS] [--tiling TILING]
+       [--svg FILE] [--levels LEVELS] [--wrap WRAP]
+
+GENERATOR is one of prim (the default), kruskal, wilson, aldous-broder,
+backtracker, hunt-and-kill, recursive-division, eller, binary-tree[:BIAS],
+sidewinder[:BIAS], or growing-tree[:POLICY], where BIAS is ne (the default),
+nw, se or sw, and POLICY is newest (the default), oldest, random, or a
+weighted mix such as newest=75,random=25. SEED regenerates the exact maze
+printed alongside it. FRACTION (between 0 and 1) is the fraction of dead ends
+to remove, adding loops to the maze.
+
+The passages made by prim, backtracker, hunt-and-kill and growing-tree can be
+shaped with --horizontal-bias BIAS (the weight of horizontal passages against
+vertical ones, 0.5 by default) and --straightness STRAIGHTNESS (the chance of
+a passage carrying straight on where it could turn, left to the bias by
+default), both between 0 and 1, in mazes of square cells on a single level
+which do not wrap around.
+
+arborescence makes a maze of one-way doors, each leading away from the
+top-left cell, and cannot be braided.
+
+TILING is the shape of the cells, square (the default), hex, delta
+(triangles) or polar (rings of cells around a centre, HEIGHT rings counting
+the centre, with WIDTH cells in the first) - binary-tree, sidewinder, eller
+and recursive-division only carve square and hex cells. FILE is an SVG image
+of the maze, which is saved again with the path whenever the maze is solved.
+Polar mazes are only drawn as an image, so need --svg FILE.
+
+LEVELS is the number of levels of square cells stacked one above the other,
+joined by stairs marked U (up), D (down) or X (both) - one level is shown at
+a time, and the maze is solved from the first level to the last.
+
+WRAP is none (the default), horizontal or vertical (a cylinder, whose east
+and west, or north and south, edges lead around to each other), or both (a
+torus), for mazes of square cells on a single level.";
+static MENU: &str = "\nEnter choice (1 - solve, 2 - longest path, 3 - quit)... \n";
+static LEVELS_MENU: &str =
+    "\nEnter choice (1 - solve, 2 - longest path, 3 - quit, 4 - level up, 5 - level down)... \n";
 
 ///
 /// The entry-point for the maze project.
 ///
 fn main() {
-    let args = io::get_args();
+    let mut args = io::get_args();
+
+    // remove the options, leaving only the dimensions and the generator
+    let options = helper::take_seed(&mut args).and_then(|seed| {
+        let braid = helper::take_braid(&mut args)?;
+        let texture = helper::take_texture(&mut args)?;
+        let tiling = helper::take_tiling(&mut args)?;
+        let svg = helper::take_svg(&mut args)?;
+        let levels = helper::take_levels(&mut args)?.unwrap_or(1);
+        let wrap = helper::take_wrap(&mut args)?.unwrap_or_default();
+
+        let tiling = tiling.unwrap_or_default();
+        if !tiling.is_drawable_on_terminal() && svg.is_none() {
+            return Err(MazeError::of(ErrorKind::MissingOutputFile));
+        }
+        if levels > 1 && (tiling != Tiling::Square || svg.is_some()) {
+            return Err(MazeError::of(ErrorKind::InvalidLevels));
+        }
+        // the texture tells passages along the rows of square cells from
+        // those along the columns, which other cells, wrapped edges and
+        // stairs between levels lack
+        if texture.is_some() && (tiling != Tiling::Square || wrap != Wrap::None || levels > 1) {
+            return Err(MazeError::of(ErrorKind::InvalidGenerator));
+        }
+        Ok((seed, braid, texture, tiling, svg, levels, wrap))
+    });
+
+    let (seed, braid, texture, tiling, svg, levels, wrap) = match options {
+        Ok(options) => options,
+        Err(e) => io::print_error_and_quit(Box::new(e)),
+    };
 
-    if args.len() != 2 {
+    if args.len() != 2 && args.len() != 3 {
         io::print_message_and_quit(USAGE);
     }
 
-    // validate the supplied dimensions
-    match helper::get_maze_dimensions(&args) {
-        Ok((h, w)) => {
+    // validate the supplied dimensions and generator
+    let options = helper::get_maze_dimensions(&args).and_then(|(h, w)| {
+        let name = args.get(2).map_or("prim", |s| s.as_str());
+        let generator = helper::get_generator(name, h, w, texture)?;
+        Ok((h, w, generator))
+    });
+
+    match options {
+        Ok((h, w, generator)) => {
             // generate maze with the given dimensions
-            let mut maze = core::Maze::initialize_maze(h, w);
-            maze.create_maze();
+            let mut maze = if levels > 1 {
+                core::Maze::initialize_maze_with_levels(h, w, levels)
+            } else {
+                core::Maze::initialize_maze_with(h, w, tiling)
+            };
+            if let Some(seed) = seed {
+                maze.set_seed(seed);
+            }
+            if let Some(braid) = braid {
+                maze.set_braid(braid);
+            }
+            maze.set_wrap(wrap);
+            if let Err(e) = maze.create_maze_with(generator.as_ref()) {
+                io::print_error_and_quit(Box::new(e));
+            }
+
+            // keep the image in step with the maze on the screen
+            let save_svg = |maze: &core::Maze| {
+                if let Some(filename) = &svg {
+                    if let Err(e) = maze.save_svg(filename) {
+                        io::print_error_and_quit(Box::new(e));
+                    }
+                }
+            };
+            save_svg(&maze);
 
             loop {
-                io::print_message(MENU);
+                io::print_message(if levels > 1 { LEVELS_MENU } else { MENU });
 
                 if let Some(option) = io::get_number() {
-                    match option {
+                    let level = maze.get_level();
+                    let result = match option {
                         1 => maze.solve_maze(),
                         2 => maze.print_longest_path(),
                         3 => io::print_message_and_quit("Goodbye!\n"),
+                        4 if level + 1 < levels => maze.show_level(level + 1),
+                        5 if level > 0 => maze.show_level(level - 1),
                         _ => continue,
+                    };
+                    if let Err(e) = result {
+                        io::print_error_and_quit(Box::new(e));
                     }
+                    save_svg(&maze);
                 }
                 // avoid scrolling down the menu
                 io::adjust_menu_location_on_screen();